@@ -5,6 +5,7 @@ use syn::{parse_macro_input, ItemFn, ReturnType};
 mod mir_lowering;
 mod shell_ast;
 mod shell_emitter;
+pub mod shell_ir;
 mod verification;
 
 use crate::verification::{verify_determinism, verify_posix_compliance};
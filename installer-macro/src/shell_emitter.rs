@@ -0,0 +1,223 @@
+//! Deterministic text emission for the generated POSIX installer.
+//!
+//! Translates the [`ShellAst`] produced by [`crate::mir_lowering`] into
+//! [`crate::shell_ir`] nodes and hands them to [`shell_ir::render`] — the one
+//! place shell text is assembled. Assignments and `$(...)` substitutions are
+//! built here from words rendered by [`shell_ir::render_word`], so their
+//! quoting still comes from the IR. `if` conditions arrive from
+//! `mir_lowering` as finished shell strings and are passed through unchanged
+//! as [`ShellWord::Raw`].
+
+use crate::shell_ast::{Expression, ShellAst, Statement, Test};
+use crate::shell_ir::{self, Command, IrNode, ShellWord};
+
+pub fn emit_deterministic(ast: &ShellAst) -> String {
+    let ShellAst::Script {
+        constants, main, ..
+    } = ast;
+
+    let mut nodes = vec![IrNode::Command(Command::new(vec![ShellWord::Raw(
+        "set -euf".to_string(),
+    )]))];
+
+    for (value, id) in constants {
+        nodes.push(readonly_assignment(&format!("_s{id}"), &Expression::Literal(value.clone())));
+    }
+
+    nodes.extend(main.iter().map(lower_statement));
+
+    let mut out = String::from("#!/bin/sh\n");
+    out.push_str(&shell_ir::render(&nodes));
+    out
+}
+
+fn lower_statement(stmt: &Statement) -> IrNode {
+    match stmt {
+        Statement::Assignment { var, value } => assignment(var, value, false),
+        Statement::LocalAssignment { var, value } => assignment(var, value, false),
+        Statement::Command { cmd, args } => {
+            let mut argv = vec![ShellWord::Raw(cmd.clone())];
+            argv.extend(args.iter().map(lower_expression));
+            IrNode::Command(Command::new(argv))
+        }
+        Statement::Conditional {
+            test,
+            then_block,
+            else_block,
+        } => IrNode::If {
+            condition: lower_test(test),
+            then_branch: then_block.iter().map(lower_statement).collect(),
+            else_branch: else_block
+                .as_ref()
+                .map(|block| block.iter().map(lower_statement).collect())
+                .unwrap_or_default(),
+        },
+        Statement::Case { expr, patterns } => IrNode::Case {
+            subject: lower_expression(expr),
+            arms: patterns
+                .iter()
+                .map(|(pattern, body)| (pattern.clone(), body.iter().map(lower_statement).collect()))
+                .collect(),
+        },
+        Statement::Exit { code } => IrNode::Command(Command::new(vec![
+            ShellWord::Raw("exit".to_string()),
+            ShellWord::Raw(code.to_string()),
+        ])),
+        Statement::Return { code } => IrNode::Command(Command::new(vec![
+            ShellWord::Raw("return".to_string()),
+            ShellWord::Raw(code.to_string()),
+        ])),
+        Statement::Comment { text } => IrNode::Comment(text.clone()),
+        Statement::If {
+            condition,
+            then_block,
+            else_block,
+        } => IrNode::If {
+            condition: Command::new(vec![ShellWord::Raw(condition.clone())]),
+            then_branch: then_block.iter().map(lower_statement).collect(),
+            else_branch: else_block
+                .as_ref()
+                .map(|block| block.iter().map(lower_statement).collect())
+                .unwrap_or_default(),
+        },
+        Statement::SetTrap { command, signals } => IrNode::Trap {
+            command: ShellWord::Literal(command.clone()),
+            signals: signals.clone(),
+        },
+    }
+}
+
+fn lower_test(test: &Test) -> Command {
+    match test {
+        Test::FileExists(path) => test_command("-e", path),
+        Test::DirectoryExists(path) => test_command("-d", path),
+        Test::StringEquals(a, b) => string_test_command("=", a, b),
+        Test::StringNotEquals(a, b) => string_test_command("!=", a, b),
+        Test::CommandSuccess(cmd, args) => {
+            let mut argv = vec![ShellWord::Raw(cmd.clone())];
+            argv.extend(args.iter().map(|arg| ShellWord::Literal(arg.clone())));
+            Command::new(argv)
+        }
+        Test::Not(inner) => {
+            let mut argv = vec![ShellWord::Raw("!".to_string())];
+            argv.extend(lower_test(inner).argv);
+            Command::new(argv)
+        }
+    }
+}
+
+fn test_command(flag: &str, path: &str) -> Command {
+    Command::new(vec![
+        ShellWord::Raw("[".to_string()),
+        ShellWord::Raw(flag.to_string()),
+        ShellWord::Literal(path.to_string()),
+        ShellWord::Raw("]".to_string()),
+    ])
+}
+
+fn string_test_command(op: &str, a: &str, b: &str) -> Command {
+    Command::new(vec![
+        ShellWord::Raw("[".to_string()),
+        ShellWord::Literal(a.to_string()),
+        ShellWord::Raw(op.to_string()),
+        ShellWord::Literal(b.to_string()),
+        ShellWord::Raw("]".to_string()),
+    ])
+}
+
+fn assignment(var: &str, value: &Expression, readonly: bool) -> IrNode {
+    if readonly {
+        readonly_assignment(var, value)
+    } else {
+        IrNode::Command(Command::new(vec![ShellWord::Raw(format!(
+            "{var}={}",
+            shell_ir::render_word(&lower_expression(value))
+        ))]))
+    }
+}
+
+fn readonly_assignment(var: &str, value: &Expression) -> IrNode {
+    IrNode::Command(Command::new(vec![
+        ShellWord::Raw("readonly".to_string()),
+        ShellWord::Raw(format!(
+            "{var}={}",
+            shell_ir::render_word(&lower_expression(value))
+        )),
+    ]))
+}
+
+/// Collapse a list of already-lowered fragments into a single [`ShellWord`]:
+/// a lone fragment is returned as-is, and two or more become a
+/// [`ShellWord::Segmented`] so literal parts stay quoted while variable
+/// parts still expand at runtime.
+fn segmented_word(mut parts: Vec<ShellWord>) -> ShellWord {
+    match parts.len() {
+        0 => ShellWord::Literal(String::new()),
+        1 => parts.remove(0),
+        _ => ShellWord::Segmented(parts),
+    }
+}
+
+fn lower_expression(expr: &Expression) -> ShellWord {
+    match expr {
+        Expression::Literal(s) => ShellWord::Literal(s.clone()),
+        Expression::Variable(v) => ShellWord::VarExpansion(v.clone()),
+        Expression::Concat(parts) => segmented_word(parts.iter().map(lower_expression).collect()),
+        Expression::StringInterpolation { parts } => segmented_word(
+            parts
+                .iter()
+                .map(|part| match part {
+                    crate::shell_ast::InterpolationPart::Literal(s) => ShellWord::Literal(s.clone()),
+                    crate::shell_ast::InterpolationPart::Variable(v) => {
+                        ShellWord::VarExpansion(v.clone())
+                    }
+                })
+                .collect(),
+        ),
+        Expression::CommandSubstitution { command, args } => {
+            let mut rendered = format!("$({command}");
+            for arg in args {
+                rendered.push(' ');
+                rendered.push_str(&shell_ir::render_word(&ShellWord::Literal(arg.clone())));
+            }
+            rendered.push(')');
+            ShellWord::Raw(rendered)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell_ast::InterpolationPart;
+
+    #[test]
+    fn concat_with_a_variable_part_still_expands_at_runtime() {
+        let expr = Expression::Concat(vec![
+            Expression::Literal("hello, ".to_string()),
+            Expression::Variable("NAME".to_string()),
+        ]);
+        let rendered = shell_ir::render_word(&lower_expression(&expr));
+
+        // The variable must be an actual expansion, not inert text inside a
+        // single-quoted literal.
+        assert!(rendered.contains("\"$NAME\""));
+        assert!(!rendered.contains("'${NAME}'"));
+        assert!(!rendered.contains("'$NAME'"));
+    }
+
+    #[test]
+    fn string_interpolation_with_a_variable_part_still_expands_at_runtime() {
+        let expr = Expression::StringInterpolation {
+            parts: vec![
+                InterpolationPart::Literal("path: ".to_string()),
+                InterpolationPart::Variable("TARGET".to_string()),
+            ],
+        };
+        let rendered = shell_ir::render_word(&lower_expression(&expr));
+
+        assert!(rendered.contains("\"$TARGET\""));
+        assert!(!rendered.contains("'${TARGET}'"));
+        assert!(!rendered.contains("'$TARGET'"));
+    }
+}
@@ -0,0 +1,288 @@
+#![allow(dead_code)]
+
+//! Safe shell-codegen intermediate representation for the generated POSIX
+//! installer.
+//!
+//! Every fragment of generated shell text is built as an [`IrNode`] tree and
+//! turned into source by a single [`render`] pass. Data carried in a
+//! [`ShellWord::Literal`] is always single quoted, a
+//! [`ShellWord::VarExpansion`] is always double quoted, and an
+//! [`IrNode::TempFile`] always gets `mktemp` plus an `EXIT` trap.
+//! [`ShellWord::Raw`] text is emitted verbatim and gets no quoting.
+
+use std::fmt::Write as _;
+
+/// A single word in a command line, tagged with how it must be rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShellWord {
+    /// An opaque value, always emitted single-quoted with embedded single
+    /// quotes escaped as `'\''`. Safe for arbitrary attacker-controlled text,
+    /// including shell metacharacters, control bytes, and the `\u{202e}`
+    /// right-to-left override.
+    Literal(String),
+    /// A `$var` expansion, always emitted double-quoted (`"$var"`).
+    VarExpansion(String),
+    /// Already-valid shell syntax, emitted verbatim with no quoting. The
+    /// emitter uses it for keywords, operators and command names (`set`,
+    /// `-d`, `exit`, ...), for `var=<rendered word>` and `$(...)` fragments
+    /// it assembles from rendered words, and for `if` condition strings that
+    /// `mir_lowering` hands over already built.
+    Raw(String),
+    /// Several fragments concatenated into a single word with no separating
+    /// whitespace, e.g. `'prefix-'"$VAR"'-suffix'`. POSIX shell joins
+    /// adjacent quoted/unquoted fragments into one word, so this is how a
+    /// literal-plus-variable expression (`Concat`/`StringInterpolation`)
+    /// keeps its literal parts single-quoted while still letting `$VAR`
+    /// actually expand — a single `Literal` can't do that, since everything
+    /// inside single quotes is inert.
+    Segmented(Vec<ShellWord>),
+}
+
+/// A single command invocation: `argv[0] argv[1] ...`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    pub argv: Vec<ShellWord>,
+}
+
+impl Command {
+    pub fn new(argv: Vec<ShellWord>) -> Self {
+        Self { argv }
+    }
+}
+
+/// A node in the installer's shell IR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IrNode {
+    Command(Command),
+    If {
+        condition: Command,
+        then_branch: Vec<IrNode>,
+        else_branch: Vec<IrNode>,
+    },
+    Case {
+        subject: ShellWord,
+        arms: Vec<(String, Vec<IrNode>)>,
+    },
+    Trap {
+        command: ShellWord,
+        signals: Vec<String>,
+    },
+    /// Allocates `var=$(mktemp)` and registers an `EXIT` trap that removes it.
+    TempFile {
+        var: String,
+    },
+    Comment(String),
+}
+
+/// Render a sequence of [`IrNode`]s into POSIX shell source.
+pub fn render(nodes: &[IrNode]) -> String {
+    let mut out = String::new();
+    render_block(&mut out, nodes, 0);
+    out
+}
+
+/// Render a single [`ShellWord`] in isolation, e.g. for embedding inside a
+/// larger hand-assembled argv entry such as `var=<rendered value>`.
+pub fn render_word(word: &ShellWord) -> String {
+    let mut out = String::new();
+    write_word(&mut out, word);
+    out
+}
+
+fn render_block(out: &mut String, nodes: &[IrNode], indent: usize) {
+    for node in nodes {
+        render_node(out, node, indent);
+    }
+}
+
+fn render_node(out: &mut String, node: &IrNode, indent: usize) {
+    match node {
+        IrNode::Command(cmd) => {
+            push_indent(out, indent);
+            write_argv(out, &cmd.argv);
+            out.push('\n');
+        }
+        IrNode::Comment(text) => {
+            push_indent(out, indent);
+            let _ = write!(out, "# {text}\n");
+        }
+        IrNode::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            push_indent(out, indent);
+            out.push_str("if ");
+            write_argv(out, &condition.argv);
+            out.push_str("; then\n");
+            render_block(out, then_branch, indent + 1);
+            if !else_branch.is_empty() {
+                push_indent(out, indent);
+                out.push_str("else\n");
+                render_block(out, else_branch, indent + 1);
+            }
+            push_indent(out, indent);
+            out.push_str("fi\n");
+        }
+        IrNode::Case { subject, arms } => {
+            push_indent(out, indent);
+            out.push_str("case ");
+            write_word(out, subject);
+            out.push_str(" in\n");
+            for (pattern, body) in arms {
+                push_indent(out, indent + 1);
+                let _ = write!(out, "{pattern})\n");
+                render_block(out, body, indent + 2);
+                push_indent(out, indent + 2);
+                out.push_str(";;\n");
+            }
+            push_indent(out, indent);
+            out.push_str("esac\n");
+        }
+        IrNode::Trap { command, signals } => {
+            push_indent(out, indent);
+            out.push_str("trap ");
+            write_word(out, command);
+            for signal in signals {
+                out.push(' ');
+                out.push_str(signal);
+            }
+            out.push('\n');
+        }
+        IrNode::TempFile { var } => {
+            push_indent(out, indent);
+            let _ = write!(out, "{var}=\"$(mktemp)\"\n");
+            push_indent(out, indent);
+            let _ = write!(out, "trap 'rm -f \"${var}\"' EXIT\n");
+        }
+    }
+}
+
+fn write_argv(out: &mut String, argv: &[ShellWord]) {
+    for (i, word) in argv.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        write_word(out, word);
+    }
+}
+
+fn write_word(out: &mut String, word: &ShellWord) {
+    match word {
+        ShellWord::Literal(s) => write_single_quoted(out, s),
+        ShellWord::VarExpansion(name) => {
+            out.push_str("\"$");
+            if name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+                out.push_str(name);
+            } else {
+                out.push('{');
+                out.push_str(name);
+                out.push('}');
+            }
+            out.push('"');
+        }
+        ShellWord::Raw(s) => out.push_str(s),
+        ShellWord::Segmented(parts) => {
+            for part in parts {
+                write_word(out, part);
+            }
+        }
+    }
+}
+
+/// Emit `s` single-quoted, escaping embedded single quotes as `'\''`. Single
+/// quoting is POSIX-literal — nothing inside, including `$`, backticks, other
+/// quotes, newlines, control bytes, or the RTL override, is interpreted.
+fn write_single_quoted(out: &mut String, s: &str) {
+    out.push('\'');
+    for ch in s.chars() {
+        if ch == '\'' {
+            out.push_str("'\\''");
+        } else {
+            out.push(ch);
+        }
+    }
+    out.push('\'');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_with_metacharacters_is_single_quoted() {
+        let word = ShellWord::Literal("$(rm -rf /); `echo hi`; \"quoted\"".to_string());
+        let rendered = render_word(&word);
+        assert!(rendered.starts_with('\''));
+        assert!(rendered.ends_with('\''));
+        // Nothing inside a single-quoted string is interpreted by the shell.
+        assert!(rendered.contains("$(rm -rf /)"));
+    }
+
+    #[test]
+    fn embedded_single_quote_is_escaped() {
+        let word = ShellWord::Literal("it's a trap".to_string());
+        let rendered = render_word(&word);
+        assert_eq!(rendered, "'it'\\''s a trap'");
+    }
+
+    #[test]
+    fn rtl_override_is_single_quoted_unmodified() {
+        let word = ShellWord::Literal("file\u{202e}.txt".to_string());
+        let rendered = render_word(&word);
+        assert_eq!(rendered, "'file\u{202e}.txt'");
+    }
+
+    #[test]
+    fn segmented_word_quotes_literals_and_expands_variables() {
+        let word = ShellWord::Segmented(vec![
+            ShellWord::Literal("hello, ".to_string()),
+            ShellWord::VarExpansion("NAME".to_string()),
+            ShellWord::Literal("!".to_string()),
+        ]);
+        let rendered = render_word(&word);
+        assert_eq!(rendered, "'hello, '\"$NAME\"'!'");
+    }
+
+    #[test]
+    fn var_expansion_is_always_double_quoted() {
+        let rendered = render_word(&ShellWord::VarExpansion("TARGET".to_string()));
+        assert_eq!(rendered, "\"$TARGET\"");
+    }
+
+    #[test]
+    fn command_renders_argv_space_separated() {
+        let nodes = vec![IrNode::Command(Command::new(vec![
+            ShellWord::Raw("echo".to_string()),
+            ShellWord::Literal("hello world".to_string()),
+        ]))];
+        assert_eq!(render(&nodes), "echo 'hello world'\n");
+    }
+
+    #[test]
+    fn temp_file_emits_mktemp_and_exit_trap() {
+        let nodes = vec![IrNode::TempFile {
+            var: "TMP".to_string(),
+        }];
+        let rendered = render(&nodes);
+        assert!(rendered.contains("TMP=\"$(mktemp)\""));
+        assert!(rendered.contains("trap 'rm -f \"$TMP\"' EXIT"));
+    }
+
+    #[test]
+    fn render_never_emits_dangerous_constructs() {
+        let nodes = vec![
+            IrNode::Command(Command::new(vec![
+                ShellWord::Raw("echo".to_string()),
+                ShellWord::Literal("$(evil) `evil` <<<here".to_string()),
+            ])),
+            IrNode::TempFile {
+                var: "TMP".to_string(),
+            },
+        ];
+        let rendered = render(&nodes);
+        assert!(!rendered.contains("eval "));
+        assert!(!rendered.contains("source "));
+        assert!(!rendered.contains("<<<"));
+    }
+}
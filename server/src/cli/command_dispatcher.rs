@@ -192,6 +192,11 @@ impl CommandDispatcher {
             Commands::Diagnose(args) => super::diagnose::handle_diagnose(args).await,
             Commands::Enforce(enforce_cmd) => handlers::route_enforce_command(enforce_cmd).await,
             Commands::Refactor(refactor_cmd) => Self::execute_refactor_command(refactor_cmd).await,
+            Commands::Completions { shell } => {
+                super::completions::generate(shell);
+                Ok(())
+            }
+            Commands::Complete { args } => super::completions::complete(args, server).await,
         }
     }
 
@@ -10,7 +10,8 @@ use crate::cli::{
     DeepContextOutputFormat, DefectPredictionOutputFormat, DemoProtocol, DuplicateOutputFormat,
     DuplicateType, EnforceOutputFormat, ExplainLevel, GraphMetricType, GraphMetricsOutputFormat,
     IncrementalCoverageOutputFormat, LintHotspotOutputFormat, MakefileOutputFormat,
-    NameSimilarityOutputFormat, OutputFormat, ProofAnnotationOutputFormat, PropertyTypeFilter,
+    MutationTestOutputFormat, NameSimilarityOutputFormat, OutputFormat,
+    ProofAnnotationOutputFormat, PropertyTypeFilter,
     ProvabilityOutputFormat, QualityCheckType, QualityGateOutputFormat, QualityProfile,
     RefactorAutoOutputFormat, RefactorDocsOutputFormat, RefactorMode, RefactorOutputFormat,
     ReportOutputFormat, SatdOutputFormat, SatdSeverity, SearchScope, SymbolTableOutputFormat,
@@ -355,6 +356,20 @@ pub enum Commands {
     /// Refactor code with real-time analysis or interactive mode
     #[command(subcommand)]
     Refactor(RefactorCommands),
+
+    /// Generate shell completion scripts
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+
+    /// Answer a dynamic completion request (used internally by the generated scripts)
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// Raw words of the command line being completed
+        args: Vec<String>,
+    },
 }
 
 /// Analyze subcommands
@@ -717,6 +732,45 @@ pub enum AnalyzeCommands {
         gnu_version: String,
     },
 
+    /// Run mutation testing to find untested behavior
+    Mutants {
+        /// Project path to mutate (defaults to current directory)
+        #[arg(short = 'p', long, default_value = ".")]
+        project_path: PathBuf,
+
+        /// Command used to run the project's test suite (split on whitespace and run directly, not through a shell)
+        #[arg(long, default_value = "cargo test")]
+        test_command: String,
+
+        /// Include file patterns (e.g., "**/*.rs")
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Exclude file patterns (e.g., "**/target/**")
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// Maximum number of mutants to generate
+        #[arg(long, default_value_t = 500)]
+        max_mutants: usize,
+
+        /// Per-mutant test timeout in seconds (guards against infinite-loop mutants)
+        #[arg(long, default_value_t = 60)]
+        timeout_secs: u64,
+
+        /// Output format
+        #[arg(long, short = 'f', value_enum, default_value = "markdown")]
+        format: MutationTestOutputFormat,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Show performance metrics
+        #[arg(long)]
+        perf: bool,
+    },
+
     /// Analyze provability properties using abstract interpretation
     Provability {
         /// Project path to analyze (defaults to current directory)
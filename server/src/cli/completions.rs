@@ -0,0 +1,287 @@
+//! Shell completion support
+//!
+//! Static completion scripts (generated once via `clap_complete::generate`)
+//! only know the fixed set of subcommands and flags baked in at compile
+//! time. They can't know the live template registry, and they can't suggest
+//! a `--toolchain` value, since [`Cli`] takes it as a free-form string.
+//! Rather than hand-writing per-shell completion functions that drift from
+//! what `generate`/`analyze` actually accept, the generated scripts shell
+//! back out to the hidden `__complete` subcommand below, which answers with
+//! the live candidate list for the word currently being completed.
+
+use super::commands::Cli;
+use crate::stateless_server::StatelessTemplateServer;
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+/// Render a completion script for `shell` to stdout.
+///
+/// For the shells we know how to wire up, this is a small hand-written
+/// function that shells back out to `__complete` at completion time (so
+/// candidates reflect the live template registry); clap's static
+/// `clap_complete::generate` output is still used as a fallback for any
+/// shell `clap_complete::Shell` adds that we don't have a dynamic function
+/// for yet.
+pub fn generate(shell: Shell) {
+    let mut cmd = Cli::command();
+    let bin = cmd.get_name().to_string();
+
+    match dynamic_script(shell, &bin) {
+        Some(script) => print!("{script}"),
+        None => clap_complete::generate(shell, &mut cmd, bin, &mut io::stdout()),
+    }
+    let _ = io::stdout().flush();
+}
+
+/// A hand-written completion function for `shell` that calls `<bin>
+/// __complete` for its candidates, or `None` if we don't have one for this
+/// shell yet (callers fall back to clap's static generator).
+fn dynamic_script(shell: Shell, bin: &str) -> Option<String> {
+    match shell {
+        Shell::Bash => Some(format!(
+            r#"_{bin}_complete() {{
+    local cur words
+    words=("${{COMP_WORDS[@]}}")
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    COMPREPLY=($(compgen -W "$({bin} __complete "${{words[@]}}" 2>/dev/null)" -- "$cur"))
+}}
+complete -F _{bin}_complete {bin}
+"#
+        )),
+        Shell::Zsh => Some(format!(
+            r#"#compdef {bin}
+_{bin}_complete() {{
+    local -a candidates
+    candidates=(${{(f)"$({bin} __complete ${{words[@]}} 2>/dev/null)"}})
+    _describe 'candidates' candidates
+}}
+compdef _{bin}_complete {bin}
+"#
+        )),
+        Shell::Fish => Some(format!(
+            r#"function __{bin}_complete
+    {bin} __complete (commandline -opc) (commandline -ct)
+end
+complete -c {bin} -f -a '(__{bin}_complete)'
+"#
+        )),
+        Shell::PowerShell => Some(format!(
+            r#"Register-ArgumentCompleter -Native -CommandName {bin} -ScriptBlock {{
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $words = $commandAst.CommandElements | ForEach-Object {{ $_.ToString() }}
+    & {bin} __complete @words | Where-Object {{ $_ -like "$wordToComplete*" }} | ForEach-Object {{
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+    }}
+}}
+"#
+        )),
+        Shell::Elvish => Some(format!(
+            r#"set edit:completion:arg-completer[{bin}] = {{|@words|
+    put (all [({bin} __complete $@words)])
+}}
+"#
+        )),
+        _ => None,
+    }
+}
+
+/// Answer a dynamic completion request.
+///
+/// `words` is the full command line being completed, split on whitespace,
+/// with the binary name in position 0 and the (possibly empty) word
+/// currently being typed last. One candidate is printed per line so the
+/// calling shell function can feed them straight to `compgen`/`compadd`.
+pub async fn complete(words: Vec<String>, server: Arc<StatelessTemplateServer>) -> anyhow::Result<()> {
+    for candidate in candidates_for(&words, server).await? {
+        println!("{candidate}");
+    }
+    Ok(())
+}
+
+/// The values offered after `--toolchain`/`-t`: the same strings as
+/// `Toolchain::as_str` in `models::template`.
+const TOOLCHAINS: &[&str] = &["rust", "deno", "python-uv"];
+
+/// The `analyze` subcommand names, in the same order they're declared on
+/// [`super::commands::AnalyzeCommands`].
+const ANALYZE_KINDS: &[&str] = &[
+    "churn",
+    "complexity",
+    "dag",
+    "dead-code",
+    "satd",
+    "deep-context",
+    "tdg",
+    "lint-hotspot",
+    "makefile",
+    "mutants",
+    "provability",
+    "duplicates",
+    "defect-prediction",
+    "comprehensive",
+    "graph-metrics",
+    "name-similarity",
+    "proof-annotations",
+    "incremental-coverage",
+    "symbol-table",
+    "big-o",
+    "assembly-script",
+    "web-assembly",
+];
+
+enum CompletionContext {
+    /// Completing the `category` positional of `generate`.
+    TemplateCategory,
+    /// Completing the `template` positional, scoped to an already-typed category.
+    TemplateName { category: String },
+    Toolchain,
+    AnalyzeKind,
+    None,
+}
+
+fn completion_context(words: &[String]) -> CompletionContext {
+    if words.iter().any(|w| w == "--toolchain" || w == "-t") {
+        return CompletionContext::Toolchain;
+    }
+
+    match words {
+        [first, ..] if first == "generate" || first == "gen" || first == "g" => {
+            match words.len() {
+                0 | 1 => CompletionContext::TemplateCategory,
+                _ => CompletionContext::TemplateName {
+                    category: words[1].clone(),
+                },
+            }
+        }
+        [first, ..] if first == "analyze" => CompletionContext::AnalyzeKind,
+        _ => CompletionContext::None,
+    }
+}
+
+async fn candidates_for(
+    words: &[String],
+    server: Arc<StatelessTemplateServer>,
+) -> anyhow::Result<Vec<String>> {
+    // words[0] is the binary name; drop it so positions line up with the
+    // subcommand's own arguments (words[0] == "generate", words[1] == category, ...).
+    let words = if words.is_empty() { words } else { &words[1..] };
+
+    match completion_context(words) {
+        CompletionContext::TemplateCategory => template_categories(server).await,
+        CompletionContext::TemplateName { category } => template_names(server, &category).await,
+        CompletionContext::Toolchain => Ok(TOOLCHAINS.iter().map(|s| s.to_string()).collect()),
+        CompletionContext::AnalyzeKind => Ok(ANALYZE_KINDS.iter().map(|s| s.to_string()).collect()),
+        CompletionContext::None => Ok(Vec::new()),
+    }
+}
+
+async fn template_categories(server: Arc<StatelessTemplateServer>) -> anyhow::Result<Vec<String>> {
+    use crate::services::template_service::list_templates;
+
+    let templates = list_templates(server.as_ref(), None, None).await?;
+    let mut categories: Vec<String> = templates
+        .iter()
+        .map(|t| t.category.as_str().to_string())
+        .collect();
+    categories.sort_unstable();
+    categories.dedup();
+    Ok(categories)
+}
+
+async fn template_names(
+    server: Arc<StatelessTemplateServer>,
+    category: &str,
+) -> anyhow::Result<Vec<String>> {
+    use crate::services::template_service::list_templates;
+
+    let templates = list_templates(server.as_ref(), None, Some(category)).await?;
+    Ok(templates
+        .iter()
+        .filter_map(|t| {
+            t.uri
+                .trim_start_matches("template://")
+                .strip_prefix(category)
+                .map(|rest| rest.trim_start_matches('/').to_string())
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_context_generate_category() {
+        let words = vec!["generate".to_string()];
+        assert!(matches!(
+            completion_context(&words),
+            CompletionContext::TemplateCategory
+        ));
+    }
+
+    #[test]
+    fn test_completion_context_generate_template() {
+        let words = vec!["generate".to_string(), "makefile".to_string()];
+        match completion_context(&words) {
+            CompletionContext::TemplateName { category } => assert_eq!(category, "makefile"),
+            _ => panic!("expected TemplateName"),
+        }
+    }
+
+    #[test]
+    fn test_completion_context_analyze_kind() {
+        let words = vec!["analyze".to_string()];
+        assert!(matches!(
+            completion_context(&words),
+            CompletionContext::AnalyzeKind
+        ));
+    }
+
+    #[test]
+    fn test_completion_context_toolchain_flag() {
+        let words = vec!["scaffold".to_string(), "--toolchain".to_string()];
+        assert!(matches!(
+            completion_context(&words),
+            CompletionContext::Toolchain
+        ));
+    }
+
+    #[test]
+    fn test_dynamic_script_bash_calls_back_into_complete() {
+        let script = dynamic_script(Shell::Bash, "pmat").unwrap();
+        assert!(script.contains("pmat __complete"));
+        assert!(script.contains("complete -F _pmat_complete pmat"));
+    }
+
+    #[test]
+    fn test_dynamic_script_zsh_calls_back_into_complete() {
+        let script = dynamic_script(Shell::Zsh, "pmat").unwrap();
+        assert!(script.contains("pmat __complete"));
+    }
+
+    #[test]
+    fn test_dynamic_script_fish_calls_back_into_complete() {
+        let script = dynamic_script(Shell::Fish, "pmat").unwrap();
+        assert!(script.contains("pmat __complete"));
+    }
+
+    #[test]
+    fn test_dynamic_script_powershell_calls_back_into_complete() {
+        let script = dynamic_script(Shell::PowerShell, "pmat").unwrap();
+        assert!(script.contains("& pmat __complete"));
+    }
+
+    #[test]
+    fn test_dynamic_script_elvish_calls_back_into_complete() {
+        let script = dynamic_script(Shell::Elvish, "pmat").unwrap();
+        assert!(script.contains("pmat __complete"));
+    }
+
+    #[test]
+    fn test_completion_context_none() {
+        let words = vec!["list".to_string()];
+        assert!(matches!(completion_context(&words), CompletionContext::None));
+    }
+}
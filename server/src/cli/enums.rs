@@ -868,6 +868,24 @@ impl fmt::Display for QualityCheckType {
     }
 }
 
+/// Mutation testing output format
+#[derive(Clone, Debug, ValueEnum, PartialEq, Serialize, Deserialize)]
+pub enum MutationTestOutputFormat {
+    /// JSON format for tooling integration
+    Json,
+    /// Markdown report format
+    Markdown,
+}
+
+impl fmt::Display for MutationTestOutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MutationTestOutputFormat::Json => write!(f, "json"),
+            MutationTestOutputFormat::Markdown => write!(f, "markdown"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -915,6 +933,7 @@ mod tests {
         assert_eq!(DemoProtocol::Http.to_string(), "http");
         assert_eq!(AnalysisType::BigO.to_string(), "big-o");
         assert_eq!(QualityCheckType::Coverage.to_string(), "coverage");
+        assert_eq!(MutationTestOutputFormat::Json.to_string(), "json");
     }
 
     #[test]
@@ -357,6 +357,30 @@ pub async fn route_analyze_command(cmd: AnalyzeCommands) -> Result<()> {
             )
             .await
         }
+        AnalyzeCommands::Mutants {
+            project_path,
+            test_command,
+            include,
+            exclude,
+            max_mutants,
+            timeout_secs,
+            format,
+            output,
+            perf,
+        } => {
+            super::mutation_handlers::handle_analyze_mutants(
+                project_path,
+                test_command,
+                include,
+                exclude,
+                max_mutants,
+                timeout_secs,
+                format,
+                output,
+                perf,
+            )
+            .await
+        }
         AnalyzeCommands::Provability {
             project_path,
             functions,
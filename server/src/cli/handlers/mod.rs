@@ -13,6 +13,7 @@ pub mod enforce_handlers;
 pub mod enhanced_reporting_handlers;
 pub mod generation_handlers;
 pub mod lint_hotspot_handlers;
+pub mod mutation_handlers;
 pub mod name_similarity_analysis;
 pub mod refactor_auto_handlers;
 pub mod refactor_docs_handlers;
@@ -39,6 +40,7 @@ pub use duplication_analysis::handle_analyze_duplicates;
 pub use enforce_handlers::route_enforce_command;
 pub use generation_handlers::{handle_generate, handle_scaffold, handle_validate};
 pub use lint_hotspot_handlers::handle_analyze_lint_hotspot;
+pub use mutation_handlers::handle_analyze_mutants;
 pub use name_similarity_analysis::handle_analyze_name_similarity;
 pub use refactor_docs_handlers::handle_refactor_docs;
 pub use refactor_handlers::{route_refactor_command, RefactorServeParams};
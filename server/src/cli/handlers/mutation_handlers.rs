@@ -0,0 +1,61 @@
+//! Mutation testing command handler
+
+use crate::cli::*;
+use crate::services::mutation_testing::{format_markdown, run_mutation_testing, MutationConfig};
+use anyhow::Result;
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// Handle the `analyze mutants` command
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_analyze_mutants(
+    project_path: PathBuf,
+    test_command: String,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    max_mutants: usize,
+    timeout_secs: u64,
+    format: MutationTestOutputFormat,
+    output: Option<PathBuf>,
+    perf: bool,
+) -> Result<()> {
+    let start_time = std::time::Instant::now();
+
+    info!("🧬 Starting mutation testing sweep");
+    info!("📂 Project path: {}", project_path.display());
+    info!("🧪 Test command: {}", test_command);
+
+    let config = MutationConfig {
+        project_path: project_path.clone(),
+        test_command,
+        include,
+        exclude,
+        max_mutants,
+        timeout: Duration::from_secs(timeout_secs),
+    };
+
+    if perf {
+        debug!("Mutation configuration: {:?}", config);
+    }
+
+    let report = tokio::task::spawn_blocking(move || run_mutation_testing(&config)).await??;
+
+    let output_content = match format {
+        MutationTestOutputFormat::Json => serde_json::to_string_pretty(&report)?,
+        MutationTestOutputFormat::Markdown => format_markdown(&report),
+    };
+
+    if let Some(output_path) = output {
+        tokio::fs::write(&output_path, &output_content).await?;
+        info!("📄 Mutation report saved to: {}", output_path.display());
+    } else {
+        println!("{output_content}");
+    }
+
+    if perf {
+        info!("⏱️  Mutation sweep took {:?}", start_time.elapsed());
+    }
+
+    Ok(())
+}
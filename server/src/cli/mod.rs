@@ -9,6 +9,7 @@ pub mod args;
 pub mod command_dispatcher;
 pub mod command_structure;
 pub mod commands;
+pub mod completions;
 pub mod coverage_helpers;
 pub mod defect_helpers;
 pub mod defect_prediction_helpers;
@@ -0,0 +1,363 @@
+//! Golden-file comparison harness with normalized diffing
+//!
+//! Snapshots the full output of a generator (e.g. `generate_template`,
+//! `scaffold_project`) against a checked-in fixture and fails with a unified
+//! line-by-line diff on mismatch. Volatile content -- the `{{current_year}}`
+//! helper, absolute paths, timestamps -- is normalized to stable placeholders
+//! like `[YEAR]`/`[ROOT]` via [`Redaction`] before comparison, so fixtures
+//! stay reviewable and reproducible across machines and days.
+//!
+//! Set `UPDATE_GOLDEN=1` to rewrite the checked-in fixtures from the actual
+//! output instead of comparing against them.
+
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GoldenError {
+    #[error("failed to read golden file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to write golden file {path}: {source}")]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(
+        "golden file {path} does not exist; rerun with UPDATE_GOLDEN=1 to create it"
+    )]
+    Missing { path: PathBuf },
+
+    #[error("output does not match golden file {path}\n{diff}")]
+    Mismatch { path: PathBuf, diff: String },
+}
+
+/// A single text substitution applied to the actual output before
+/// comparison, so volatile values don't cause spurious mismatches. Golden
+/// files are compared as stored; update mode writes them already normalized.
+#[derive(Clone)]
+pub struct Redaction {
+    pattern: Regex,
+    replacement: &'static str,
+}
+
+impl Redaction {
+    /// Build a redaction from a raw regex pattern.
+    pub fn new(pattern: &str, replacement: &'static str) -> Self {
+        Self {
+            pattern: Regex::new(pattern).expect("invalid redaction pattern"),
+            replacement,
+        }
+    }
+
+    fn apply(&self, text: &str) -> String {
+        self.pattern.replace_all(text, self.replacement).into_owned()
+    }
+
+    /// Replaces a four-digit year (as produced by the `{{current_year}}`
+    /// template helper) with `[YEAR]`.
+    pub fn current_year() -> Self {
+        Self::new(r"\b(19|20)\d{2}\b", "[YEAR]")
+    }
+
+    /// Replaces absolute filesystem paths of two or more components (e.g.
+    /// `/home/user/project`; a bare `/tmp` is left alone) with `[ROOT]`, so
+    /// fixtures generated under different checkout locations still compare equal.
+    pub fn absolute_paths() -> Self {
+        Self::new(r"(/[A-Za-z0-9_.\-]+){2,}", "[ROOT]")
+    }
+
+    /// Replaces ISO-8601-ish timestamps with `[TIMESTAMP]`.
+    pub fn timestamps() -> Self {
+        Self::new(
+            r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:?\d{2})?",
+            "[TIMESTAMP]",
+        )
+    }
+
+    /// Replaces the value of `$USER` / `$USERNAME` wherever it appears, for
+    /// output that embeds the current user as an author.
+    pub fn author_from_env() -> Self {
+        let user = std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_default();
+        let pattern = if user.is_empty() {
+            r"\b\B".to_string() // matches nothing
+        } else {
+            regex::escape(&user)
+        };
+        Self::new(&pattern, "[AUTHOR]")
+    }
+
+    /// The default redaction set: current year, absolute paths, timestamps,
+    /// and the invoking user's name.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::timestamps(),
+            Self::current_year(),
+            Self::absolute_paths(),
+            Self::author_from_env(),
+        ]
+    }
+}
+
+/// A golden-file comparison for a single piece of generated output.
+///
+/// ```no_run
+/// use paiml_mcp_agent_toolkit::golden::GoldenTest;
+///
+/// # let generated_output = String::new();
+/// GoldenTest::new("scaffold/rust_lib.txt")
+///     .compare(&generated_output)
+///     .unwrap();
+/// ```
+pub struct GoldenTest {
+    path: PathBuf,
+    redactions: Vec<Redaction>,
+    update: bool,
+}
+
+impl GoldenTest {
+    /// `name` is a path relative to `server/tests/golden/`.
+    pub fn new(name: &str) -> Self {
+        Self::at(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden"), name)
+    }
+
+    /// Like [`Self::new`], but rooted at an explicit golden directory --
+    /// useful for downstream template authors whose crate root differs.
+    pub fn at(golden_dir: impl Into<PathBuf>, name: &str) -> Self {
+        Self {
+            path: golden_dir.into().join(name),
+            redactions: Redaction::defaults(),
+            update: std::env::var("UPDATE_GOLDEN").as_deref() == Ok("1"),
+        }
+    }
+
+    /// Replace the default redaction set with a caller-supplied one.
+    pub fn with_redactions(mut self, redactions: Vec<Redaction>) -> Self {
+        self.redactions = redactions;
+        self
+    }
+
+    /// Add one more redaction on top of the existing set.
+    pub fn redact(mut self, redaction: Redaction) -> Self {
+        self.redactions.push(redaction);
+        self
+    }
+
+    /// Explicitly override whether [`Self::compare`] rewrites the golden
+    /// file, ignoring whatever `UPDATE_GOLDEN` was set to when this
+    /// `GoldenTest` was built. `compare` reads the `update` flag captured
+    /// at construction rather than the environment directly, so this is
+    /// the only supported way to flip it -- tests of the harness itself
+    /// (or any caller running comparisons concurrently on other threads)
+    /// never need to mutate the process-global `UPDATE_GOLDEN` env var,
+    /// which `std::env::set_var` would otherwise make visible to any other
+    /// `compare` call racing on another test thread.
+    pub fn force_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    fn normalize(&self, text: &str) -> String {
+        let mut normalized = text.to_string();
+        for redaction in &self.redactions {
+            normalized = redaction.apply(&normalized);
+        }
+        normalized
+    }
+
+    /// Compare `actual` against the checked-in golden file.
+    ///
+    /// In update mode (`UPDATE_GOLDEN=1` when this `GoldenTest` was built, or
+    /// [`Self::force_update`]), the golden file is (re)written from `actual`
+    /// instead, and this returns `Ok` unless the write fails.
+    pub fn compare(&self, actual: &str) -> Result<(), GoldenError> {
+        let normalized_actual = self.normalize(actual);
+
+        if self.update {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent).map_err(|source| GoldenError::Write {
+                    path: self.path.clone(),
+                    source,
+                })?;
+            }
+            fs::write(&self.path, &normalized_actual).map_err(|source| GoldenError::Write {
+                path: self.path.clone(),
+                source,
+            })?;
+            return Ok(());
+        }
+
+        if !self.path.exists() {
+            return Err(GoldenError::Missing {
+                path: self.path.clone(),
+            });
+        }
+
+        let expected = fs::read_to_string(&self.path).map_err(|source| GoldenError::Read {
+            path: self.path.clone(),
+            source,
+        })?;
+
+        if expected == normalized_actual {
+            return Ok(());
+        }
+
+        Err(GoldenError::Mismatch {
+            path: self.path.clone(),
+            diff: unified_diff(&expected, &normalized_actual),
+        })
+    }
+}
+
+/// A minimal unified-ish line diff: no hunk compaction, just every line
+/// annotated with its status, which is all a fixture mismatch needs to be
+/// reviewable in CI output.
+fn unified_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut out = String::from("--- golden\n+++ actual\n");
+    let max_len = expected_lines.len().max(actual_lines.len());
+
+    for i in 0..max_len {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => {
+                out.push_str("  ");
+                out.push_str(e);
+                out.push('\n');
+            }
+            (Some(e), Some(a)) => {
+                out.push_str("- ");
+                out.push_str(e);
+                out.push('\n');
+                out.push_str("+ ");
+                out.push_str(a);
+                out.push('\n');
+            }
+            (Some(e), None) => {
+                out.push_str("- ");
+                out.push_str(e);
+                out.push('\n');
+            }
+            (None, Some(a)) => {
+                out.push_str("+ ");
+                out.push_str(a);
+                out.push('\n');
+            }
+            (None, None) => {}
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn identical_content_matches() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("simple.golden"), "hello world\n").unwrap();
+
+        let test = GoldenTest::at(dir.path(), "simple.golden").with_redactions(vec![]);
+        assert!(test.compare("hello world\n").is_ok());
+    }
+
+    #[test]
+    fn mismatch_reports_diff() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("simple.golden"), "line one\nline two\n").unwrap();
+
+        let test = GoldenTest::at(dir.path(), "simple.golden").with_redactions(vec![]);
+        let err = test.compare("line one\nline CHANGED\n").unwrap_err();
+
+        match err {
+            GoldenError::Mismatch { diff, .. } => {
+                assert!(diff.contains("- line two"));
+                assert!(diff.contains("+ line CHANGED"));
+            }
+            other => panic!("expected Mismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn missing_golden_file_is_reported() {
+        let dir = tempdir().unwrap();
+        let test = GoldenTest::at(dir.path(), "nope.golden").with_redactions(vec![]);
+        assert!(matches!(
+            test.compare("anything"),
+            Err(GoldenError::Missing { .. })
+        ));
+    }
+
+    #[test]
+    fn update_golden_rewrites_the_fixture() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("rewritten.golden");
+
+        // `force_update` rather than `std::env::set_var("UPDATE_GOLDEN", ...)`
+        // -- the latter is process-global and would race with `compare()`
+        // calls on other test threads.
+        let result = GoldenTest::at(dir.path(), "rewritten.golden")
+            .with_redactions(vec![])
+            .force_update(true)
+            .compare("freshly generated\n");
+
+        assert!(result.is_ok());
+        assert_eq!(fs::read_to_string(path).unwrap(), "freshly generated\n");
+    }
+
+    #[test]
+    fn current_year_redaction_normalizes_year() {
+        let redaction = Redaction::current_year();
+        assert_eq!(redaction.apply("Copyright 2026 Acme"), "Copyright [YEAR] Acme");
+    }
+
+    #[test]
+    fn absolute_paths_redaction_normalizes_path() {
+        let redaction = Redaction::absolute_paths();
+        assert_eq!(
+            redaction.apply("Generated at /home/user/project/out.rs"),
+            "Generated at [ROOT]"
+        );
+    }
+
+    #[test]
+    fn timestamps_redaction_normalizes_iso8601() {
+        let redaction = Redaction::timestamps();
+        assert_eq!(
+            redaction.apply("built 2026-07-26T10:00:00Z"),
+            "built [TIMESTAMP]"
+        );
+    }
+
+    #[test]
+    fn redactions_compose_in_order() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("composed.golden"),
+            "Copyright [YEAR] by [AUTHOR] at [ROOT]\n",
+        )
+        .unwrap();
+
+        std::env::set_var("USER", "octocat");
+        let test = GoldenTest::at(dir.path(), "composed.golden");
+        let actual = "Copyright 2026 by octocat at /home/octocat/work\n";
+        let result = test.compare(actual);
+        std::env::remove_var("USER");
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
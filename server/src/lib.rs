@@ -1,5 +1,6 @@
 pub mod cli;
 pub mod demo;
+pub mod golden;
 pub mod handlers;
 pub mod models;
 pub mod services;
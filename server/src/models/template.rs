@@ -53,6 +53,19 @@ pub enum TemplateCategory {
     Context,
 }
 
+impl TemplateCategory {
+    /// The lowercase path segment this category maps to in a
+    /// `template://<category>/...` URI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateCategory::Makefile => "makefile",
+            TemplateCategory::Readme => "readme",
+            TemplateCategory::Gitignore => "gitignore",
+            TemplateCategory::Context => "context",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParameterSpec {
     pub name: String,
@@ -168,12 +168,7 @@ fn build_s3_object_key(
 }
 
 fn get_category_path(category: &TemplateCategory) -> &'static str {
-    match category {
-        TemplateCategory::Makefile => "makefile",
-        TemplateCategory::Readme => "readme",
-        TemplateCategory::Gitignore => "gitignore",
-        TemplateCategory::Context => "context",
-    }
+    category.as_str()
 }
 
 pub async fn list_templates(prefix: &str) -> Result<Vec<Arc<TemplateResource>>, TemplateError> {
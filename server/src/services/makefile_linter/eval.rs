@@ -0,0 +1,505 @@
+//! Evaluator for GNU Make text functions and variable expansion.
+//!
+//! The parser turns a Makefile into an [`ast::MakefileAst`] but leaves
+//! variable values and recipe text as opaque strings. This module expands
+//! that text the way `make` itself would: `$(VAR)`/`${VAR}` references are
+//! substituted, and the common text functions (`wildcard`, `patsubst`,
+//! `subst`, `filter`, `filter-out`, `foreach`, `if`) are evaluated.
+//!
+//! Recursive (`=`) variables are stored unexpanded and evaluated lazily on
+//! every reference, matching `make`'s "recursively expanded" semantics;
+//! simple (`:=`) variables are expanded once, at the point they are
+//! collected, and the result is reused for every later reference.
+
+use super::ast::{AssignmentOp, MakefileAst, NodeData};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+enum VarValue {
+    /// Already-expanded text (from a `:=` / simple assignment).
+    Simple(String),
+    /// Raw text, expanded on every reference (from a `=` / recursive assignment).
+    Recursive(String),
+}
+
+pub struct Evaluator {
+    vars: HashMap<String, VarValue>,
+    base_dir: PathBuf,
+}
+
+impl Evaluator {
+    /// Build an evaluator from a parsed Makefile's variable assignments,
+    /// resolving `wildcard` against `base_dir` (the Makefile's directory).
+    pub fn new(ast: &MakefileAst, base_dir: &Path) -> Self {
+        let mut vars = HashMap::new();
+
+        for node in &ast.nodes {
+            if let NodeData::Variable {
+                name,
+                assignment_op,
+                value,
+            } = &node.data
+            {
+                let entry = match assignment_op {
+                    AssignmentOp::Immediate => {
+                        // `:=` expands against variables defined so far.
+                        let partial = Self::expand_with(value, &vars, base_dir);
+                        VarValue::Simple(partial)
+                    }
+                    AssignmentOp::Append => {
+                        let addition = value.clone();
+                        match vars.remove(name) {
+                            Some(VarValue::Simple(existing)) => {
+                                let expanded = Self::expand_with(&addition, &vars, base_dir);
+                                VarValue::Simple(format!("{existing} {expanded}"))
+                            }
+                            Some(VarValue::Recursive(existing)) => {
+                                VarValue::Recursive(format!("{existing} {addition}"))
+                            }
+                            None => VarValue::Recursive(addition),
+                        }
+                    }
+                    AssignmentOp::Conditional => {
+                        if vars.contains_key(name) {
+                            continue;
+                        }
+                        VarValue::Recursive(value.clone())
+                    }
+                    AssignmentOp::Deferred | AssignmentOp::Shell => {
+                        VarValue::Recursive(value.clone())
+                    }
+                };
+
+                vars.insert(name.clone(), entry);
+            }
+        }
+
+        Self {
+            vars,
+            base_dir: base_dir.to_path_buf(),
+        }
+    }
+
+    /// Expand all variable references and text functions in `text`.
+    pub fn expand(&self, text: &str) -> String {
+        Self::expand_with(text, &self.vars, &self.base_dir)
+    }
+
+    /// Names of all variables this evaluator knows about (defined anywhere
+    /// in the Makefile).
+    pub fn defined_names(&self) -> impl Iterator<Item = &str> {
+        self.vars.keys().map(String::as_str)
+    }
+
+    fn expand_with(text: &str, vars: &HashMap<String, VarValue>, base_dir: &Path) -> String {
+        let mut out = String::with_capacity(text.len());
+        let bytes = text.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] == b'$' && i + 1 < bytes.len() {
+                match bytes[i + 1] {
+                    b'$' => {
+                        out.push('$');
+                        i += 2;
+                        continue;
+                    }
+                    b'(' | b'{' => {
+                        let close = if bytes[i + 1] == b'(' { b')' } else { b'}' };
+                        if let Some((inner, next)) = extract_balanced(text, i + 2, close) {
+                            out.push_str(&eval_reference(inner, vars, base_dir));
+                            i = next;
+                            continue;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // Safe to step by one byte at a time only on ASCII boundaries;
+            // fall back to char width for the rest of the text.
+            let ch = text[i..].chars().next().unwrap_or('$');
+            out.push(ch);
+            i += ch.len_utf8();
+        }
+
+        out
+    }
+}
+
+/// Find the matching closing delimiter for text starting at `start`,
+/// accounting for nested `$(...)`/`${...}`. Returns the inner slice and the
+/// byte offset just past the closing delimiter.
+///
+/// `pub(crate)` so [`super::rules::evaluation::UndefinedVariableEvalRule`]
+/// can walk the same balanced-paren structure to find nested references
+/// inside function-call arguments, without re-deriving this logic.
+pub(crate) fn extract_balanced(text: &str, start: usize, close: u8) -> Option<(&str, usize)> {
+    let open = if close == b')' { b'(' } else { b'{' };
+    let bytes = text.as_bytes();
+    let mut depth = 1usize;
+    let mut i = start;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b if b == open => depth += 1,
+            b if b == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((&text[start..i], i + 1));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Evaluate the inside of a `$(...)`/`${...}` reference: either a known
+/// text function call or a plain variable reference.
+fn eval_reference(inner: &str, vars: &HashMap<String, VarValue>, base_dir: &Path) -> String {
+    let trimmed = inner.trim_start();
+
+    if let Some(rest) = strip_function(trimmed, "wildcard") {
+        return fn_wildcard(&expand_text(rest, vars, base_dir), base_dir);
+    }
+    if let Some(rest) = strip_function(trimmed, "patsubst") {
+        let args = split_args(rest, 3);
+        if let [pattern, replacement, text] = args.as_slice() {
+            return fn_patsubst(
+                &expand_text(pattern, vars, base_dir),
+                &expand_text(replacement, vars, base_dir),
+                &expand_text(text, vars, base_dir),
+            );
+        }
+        return String::new();
+    }
+    if let Some(rest) = strip_function(trimmed, "subst") {
+        let args = split_args(rest, 3);
+        if let [from, to, text] = args.as_slice() {
+            return fn_subst(
+                &expand_text(from, vars, base_dir),
+                &expand_text(to, vars, base_dir),
+                &expand_text(text, vars, base_dir),
+            );
+        }
+        return String::new();
+    }
+    if let Some(rest) = strip_function(trimmed, "filter-out") {
+        let args = split_args(rest, 2);
+        if let [patterns, text] = args.as_slice() {
+            return fn_filter(
+                &expand_text(patterns, vars, base_dir),
+                &expand_text(text, vars, base_dir),
+                false,
+            );
+        }
+        return String::new();
+    }
+    if let Some(rest) = strip_function(trimmed, "filter") {
+        let args = split_args(rest, 2);
+        if let [patterns, text] = args.as_slice() {
+            return fn_filter(
+                &expand_text(patterns, vars, base_dir),
+                &expand_text(text, vars, base_dir),
+                true,
+            );
+        }
+        return String::new();
+    }
+    if let Some(rest) = strip_function(trimmed, "foreach") {
+        let args = split_args(rest, 3);
+        if let [var, list, body] = args.as_slice() {
+            return fn_foreach(var.trim(), &expand_text(list, vars, base_dir), body, vars, base_dir);
+        }
+        return String::new();
+    }
+    if let Some(rest) = strip_function(trimmed, "if") {
+        let args = split_args(rest, 3);
+        return fn_if(&args, vars, base_dir);
+    }
+
+    // Plain variable reference, possibly itself containing nested expansions
+    // (e.g. `$($(INNER))`).
+    let name = Evaluator::expand_with(trimmed, vars, base_dir);
+    lookup(&name, vars, base_dir)
+}
+
+fn expand_text(text: &str, vars: &HashMap<String, VarValue>, base_dir: &Path) -> String {
+    Evaluator::expand_with(text, vars, base_dir)
+}
+
+fn lookup(name: &str, vars: &HashMap<String, VarValue>, base_dir: &Path) -> String {
+    match vars.get(name) {
+        Some(VarValue::Simple(value)) => value.clone(),
+        Some(VarValue::Recursive(value)) => Evaluator::expand_with(value, vars, base_dir),
+        None => String::new(),
+    }
+}
+
+fn strip_function<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let rest = text.strip_prefix(name)?;
+    rest.strip_prefix(' ')
+}
+
+/// Split a function's comma-separated argument text into at most `max_parts`
+/// pieces, honoring nested `$(...)`/`${...}` so commas inside a nested call
+/// don't split early. The final piece absorbs any remaining commas (GNU
+/// Make functions take their last argument verbatim).
+fn split_args(text: &str, max_parts: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let bytes = text.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let ch = bytes[i];
+        if parts.len() + 1 == max_parts {
+            current.push_str(&text[i..]);
+            break;
+        }
+        match ch {
+            b'(' | b'{' => {
+                depth += 1;
+                current.push(ch as char);
+            }
+            b')' | b'}' => {
+                depth -= 1;
+                current.push(ch as char);
+            }
+            b',' if depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+            }
+            _ => current.push(ch as char),
+        }
+        i += 1;
+    }
+
+    parts.push(current);
+    parts
+}
+
+fn fn_subst(from: &str, to: &str, text: &str) -> String {
+    if from.is_empty() {
+        return text.to_string();
+    }
+    text.replace(from, to)
+}
+
+/// Convert a GNU Make `%` pattern to the stem it matched against `text`, if
+/// any.
+fn pattern_stem<'a>(pattern: &str, text: &'a str) -> Option<&'a str> {
+    let pct = pattern.find('%')?;
+    let (prefix, suffix) = (&pattern[..pct], &pattern[pct + 1..]);
+    let stem_end = text.len().checked_sub(suffix.len())?;
+    if text.starts_with(prefix) && text[stem_end..] == *suffix && stem_end >= prefix.len() {
+        Some(&text[prefix.len()..stem_end])
+    } else {
+        None
+    }
+}
+
+fn fn_patsubst(pattern: &str, replacement: &str, text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| match pattern_stem(pattern, word) {
+            Some(stem) => replacement.replacen('%', stem, 1),
+            None => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn fn_filter(patterns: &str, text: &str, keep_matching: bool) -> String {
+    let patterns: Vec<&str> = patterns.split_whitespace().collect();
+    text.split_whitespace()
+        .filter(|word| {
+            let matches = patterns
+                .iter()
+                .any(|pattern| match pattern.find('%') {
+                    Some(_) => pattern_stem(pattern, word).is_some(),
+                    None => pattern == word,
+                });
+            matches == keep_matching
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn fn_foreach(
+    var: &str,
+    list: &str,
+    body: &str,
+    vars: &HashMap<String, VarValue>,
+    base_dir: &Path,
+) -> String {
+    list.split_whitespace()
+        .map(|item| {
+            let mut scoped = vars.clone();
+            scoped.insert(var.to_string(), VarValue::Simple(item.to_string()));
+            Evaluator::expand_with(body, &scoped, base_dir)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn fn_if(args: &[String], vars: &HashMap<String, VarValue>, base_dir: &Path) -> String {
+    let condition = args.first().map(String::as_str).unwrap_or_default();
+    if !expand_text(condition, vars, base_dir).trim().is_empty() {
+        args.get(1)
+            .map(|s| expand_text(s.trim(), vars, base_dir))
+            .unwrap_or_default()
+    } else {
+        args.get(2)
+            .map(|s| expand_text(s.trim(), vars, base_dir))
+            .unwrap_or_default()
+    }
+}
+
+/// Evaluate `$(wildcard pattern)` against `base_dir`. Supports a single `*`
+/// per path, which covers the overwhelming majority of real Makefiles.
+fn fn_wildcard(pattern: &str, base_dir: &Path) -> String {
+    pattern
+        .split_whitespace()
+        .flat_map(|p| wildcard_one(p, base_dir))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn wildcard_one(pattern: &str, base_dir: &Path) -> Vec<String> {
+    let full_pattern = base_dir.join(pattern);
+    let dir = full_pattern.parent().unwrap_or(base_dir);
+    let file_pattern = full_pattern
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("");
+
+    let Some(star) = file_pattern.find('*') else {
+        return if full_pattern.exists() {
+            vec![pattern.to_string()]
+        } else {
+            vec![]
+        };
+    };
+
+    let (prefix, suffix) = (&file_pattern[..star], &file_pattern[star + 1..]);
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut matches: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            if name.starts_with(prefix) && name.ends_with(suffix) && name.len() >= prefix.len() + suffix.len() {
+                let dir_prefix = pattern.strip_suffix(file_pattern).unwrap_or("");
+                Some(format!("{dir_prefix}{name}"))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    matches.sort();
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::makefile_linter::MakefileParser;
+
+    fn evaluator_for(input: &str, dir: &Path) -> Evaluator {
+        let mut parser = MakefileParser::new(input);
+        let ast = parser.parse().unwrap();
+        Evaluator::new(&ast, dir)
+    }
+
+    #[test]
+    fn expands_simple_variable() {
+        let evaluator = evaluator_for("CC = gcc\n", Path::new("."));
+        assert_eq!(evaluator.expand("$(CC)"), "gcc");
+    }
+
+    #[test]
+    fn immediate_assignment_is_expanded_once() {
+        let evaluator = evaluator_for("A = 1\nB := $(A)\nA = 2\n", Path::new("."));
+        // B captured A's value at the point of `:=`, before the later reassignment.
+        assert_eq!(evaluator.expand("$(B)"), "1");
+        assert_eq!(evaluator.expand("$(A)"), "2");
+    }
+
+    #[test]
+    fn subst_replaces_all_occurrences() {
+        let evaluator = evaluator_for("", Path::new("."));
+        assert_eq!(evaluator.expand("$(subst .c,.o,foo.c bar.c)"), "foo.o bar.o");
+    }
+
+    #[test]
+    fn patsubst_substitutes_via_percent_stem() {
+        let evaluator = evaluator_for("", Path::new("."));
+        assert_eq!(
+            evaluator.expand("$(patsubst %.c,%.o,foo.c bar.h)"),
+            "foo.o bar.h"
+        );
+    }
+
+    #[test]
+    fn filter_keeps_only_matching_patterns() {
+        let evaluator = evaluator_for("", Path::new("."));
+        assert_eq!(
+            evaluator.expand("$(filter %.c,foo.c bar.h baz.c)"),
+            "foo.c baz.c"
+        );
+    }
+
+    #[test]
+    fn filter_out_removes_matching_patterns() {
+        let evaluator = evaluator_for("", Path::new("."));
+        assert_eq!(
+            evaluator.expand("$(filter-out %.h,foo.c bar.h baz.c)"),
+            "foo.c baz.c"
+        );
+    }
+
+    #[test]
+    fn foreach_binds_loop_variable_per_item() {
+        let evaluator = evaluator_for("", Path::new("."));
+        assert_eq!(
+            evaluator.expand("$(foreach f,a b c,[$(f)])"),
+            "[a] [b] [c]"
+        );
+    }
+
+    #[test]
+    fn if_picks_then_or_else_branch() {
+        let evaluator = evaluator_for("", Path::new("."));
+        assert_eq!(evaluator.expand("$(if yes,then,else)"), "then");
+        assert_eq!(evaluator.expand("$(if ,then,else)"), "else");
+    }
+
+    #[test]
+    fn wildcard_lists_matching_files_in_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.c"), "").unwrap();
+        std::fs::write(dir.path().join("b.c"), "").unwrap();
+        std::fs::write(dir.path().join("c.h"), "").unwrap();
+
+        let evaluator = evaluator_for("", dir.path());
+        let result = evaluator.expand("$(wildcard *.c)");
+        let mut files: Vec<&str> = result.split_whitespace().collect();
+        files.sort();
+        assert_eq!(files, vec!["a.c", "b.c"]);
+    }
+
+    #[test]
+    fn nested_function_calls_expand_inside_out() {
+        let evaluator = evaluator_for("SRCS = foo.c bar.c\n", Path::new("."));
+        assert_eq!(
+            evaluator.expand("$(patsubst %.c,%.o,$(SRCS))"),
+            "foo.o bar.o"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod ast;
+pub mod eval;
 pub mod parser;
 pub mod rules;
 
@@ -20,8 +21,10 @@ pub async fn lint_makefile(path: &Path) -> Result<LintResult, AnalysisError> {
         .parse()
         .map_err(|e| AnalysisError::ParseError(format!("Makefile parse error: {:?}", e)))?;
 
-    let registry = RuleRegistry::new();
-    let violations = registry.check_all(&ast);
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let registry = RuleRegistry::with_base_dir(base_dir);
+    let mut violations = registry.check_all(&ast);
+    violations.extend(find_recipe_separator_errors(&content));
     let quality_score = calculate_quality_score(&violations);
 
     Ok(LintResult {
@@ -31,6 +34,92 @@ pub async fn lint_makefile(path: &Path) -> Result<LintResult, AnalysisError> {
     })
 }
 
+/// Recipe lines must start with a literal tab; a space-indented line
+/// immediately following a rule header is GNU Make's classic "missing
+/// separator" mistake. This is a lexical check over the raw source rather
+/// than a [`rules::MakefileRule`], since the parser already drops
+/// space-indented "recipe" lines as unrecognized top-level text and never
+/// turns them into a [`ast::NodeData::Recipe`] to check.
+fn find_recipe_separator_errors(content: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut in_rule_body = false;
+
+    for (idx, line) in content.lines().enumerate() {
+        let line_no = idx + 1;
+
+        if line.starts_with('\t') {
+            in_rule_body = true;
+            continue;
+        }
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        if in_rule_body && line.starts_with(' ') {
+            violations.push(Violation {
+                rule: "recipe-separator".to_string(),
+                severity: Severity::Error,
+                span: ast::SourceSpan::new(0, 0, line_no, 1),
+                message: "Recipe line is indented with spaces instead of a tab".to_string(),
+                fix_hint: Some(
+                    "Replace the leading spaces with a single tab character".to_string(),
+                ),
+            });
+            continue;
+        }
+
+        in_rule_body = is_rule_header(line);
+    }
+
+    violations
+}
+
+/// Directives whose own syntax can contain a `:` that has nothing to do with
+/// a rule header, e.g. `ifeq ($(OS):$(ARCH),Linux:x86_64)`.
+const DIRECTIVE_KEYWORDS: &[&str] = &[
+    "ifeq", "ifneq", "ifdef", "ifndef", "else", "endif", "define", "endef",
+    "export", "unexport", "override", "include", "sinclude", "-include", "vpath",
+    "undefine",
+];
+
+/// Rule-header detector: a top-level, non-recipe line whose text before the
+/// first `:` looks like a target list, as opposed to:
+/// - a conditional/include directive whose own syntax contains a `:`
+///   (`ifeq ($(OS):$(ARCH),Linux:x86_64)`)
+/// - a variable assignment whose *value* happens to contain a `:`
+///   (`DOC_URL = http://example.com`) -- a target list never needs a bare
+///   `=` before the separator, so requiring its absence rules this out
+/// - the `:=` immediate-assignment operator, or a `scheme://` URL sitting
+///   right after the `:`
+fn is_rule_header(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    if DIRECTIVE_KEYWORDS
+        .iter()
+        .any(|keyword| is_directive_line(trimmed, keyword))
+    {
+        return false;
+    }
+
+    let Some(pos) = line.find(':') else {
+        return false;
+    };
+    if line.as_bytes().get(pos + 1) == Some(&b'=') {
+        return false;
+    }
+    if line[pos + 1..].starts_with("//") {
+        return false;
+    }
+
+    !line[..pos].contains('=')
+}
+
+/// Whether `trimmed` starts with `keyword` as a whole word -- i.e. followed
+/// by whitespace, `(`, or end of line, not by more identifier characters.
+fn is_directive_line(trimmed: &str, keyword: &str) -> bool {
+    trimmed
+        .strip_prefix(keyword)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(|c: char| c.is_whitespace() || c == '('))
+}
+
 fn calculate_quality_score(violations: &[Violation]) -> f32 {
     let critical_count = violations
         .iter()
@@ -154,6 +243,34 @@ mod tests {
         assert!(lint_result.violations.iter().any(|v| v.rule == "minphony"));
     }
 
+    #[test]
+    fn find_recipe_separator_errors_ignores_url_valued_assignment() {
+        let content = "DOC_URL = http://example.com\n    see above\n";
+        let violations = find_recipe_separator_errors(content);
+        assert!(
+            violations.is_empty(),
+            "a URL-valued assignment must not be mistaken for a rule header: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn find_recipe_separator_errors_ignores_ifeq_directive_with_colon() {
+        let content = "ifeq ($(OS):$(ARCH),Linux:x86_64)\n    indented text\nendif\n";
+        let violations = find_recipe_separator_errors(content);
+        assert!(
+            violations.is_empty(),
+            "an ifeq directive's own colon must not be mistaken for a rule header: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn find_recipe_separator_errors_still_flags_real_space_indented_recipe() {
+        let content = "all:\n    echo hello\n";
+        let violations = find_recipe_separator_errors(content);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, "recipe-separator");
+    }
+
     #[tokio::test]
     async fn test_lint_makefile_file_not_found() {
         let result = lint_makefile(Path::new("/nonexistent/makefile")).await;
@@ -0,0 +1,252 @@
+use super::*;
+use crate::services::makefile_linter::ast::*;
+use crate::services::makefile_linter::eval::{self, Evaluator};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// DuplicateTarget rule - warns when the same non-pattern, non-double-colon
+/// target is defined by more than one rule.
+pub struct DuplicateTargetRule;
+
+impl Default for DuplicateTargetRule {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl MakefileRule for DuplicateTargetRule {
+    fn id(&self) -> &'static str {
+        "duplicatetarget"
+    }
+
+    fn check(&self, ast: &MakefileAst) -> Vec<Violation> {
+        let mut first_seen: HashMap<&str, SourceSpan> = HashMap::new();
+        let mut violations = Vec::new();
+
+        for node in &ast.nodes {
+            let NodeData::Rule {
+                targets,
+                is_pattern,
+                is_double_colon,
+                ..
+            } = &node.data
+            else {
+                continue;
+            };
+
+            if *is_pattern || *is_double_colon {
+                continue;
+            }
+
+            for target in targets {
+                if target.starts_with('.') {
+                    continue;
+                }
+
+                match first_seen.get(target.as_str()) {
+                    Some(_) => violations.push(Violation {
+                        rule: self.id().to_string(),
+                        severity: self.default_severity(),
+                        span: node.span,
+                        message: format!("Target '{target}' is defined more than once"),
+                        fix_hint: Some(
+                            "Merge the prerequisites/recipe into a single rule, or use double-colon (::) rules if multiple recipes are intentional".to_string(),
+                        ),
+                    }),
+                    None => {
+                        first_seen.insert(target.as_str(), node.span);
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+/// UndefinedVariableEval rule - like `undefinedvariable`, but recurses into
+/// text functions' arguments to find references nested inside them, so e.g.
+/// `$(patsubst %.c,%.o,$(SRCS))` is flagged only if `SRCS` itself is
+/// undefined, not because the outer call's arguments look variable-like.
+/// Known names come from [`Evaluator::defined_names`].
+pub struct UndefinedVariableEvalRule {
+    base_dir: PathBuf,
+}
+
+impl Default for UndefinedVariableEvalRule {
+    fn default() -> Self {
+        Self {
+            base_dir: PathBuf::from("."),
+        }
+    }
+}
+
+impl UndefinedVariableEvalRule {
+    pub fn with_base_dir(base_dir: &Path) -> Self {
+        Self {
+            base_dir: base_dir.to_path_buf(),
+        }
+    }
+}
+
+impl MakefileRule for UndefinedVariableEvalRule {
+    fn id(&self) -> &'static str {
+        "undefinedvariable-eval"
+    }
+
+    fn check(&self, ast: &MakefileAst) -> Vec<Violation> {
+        let evaluator = Evaluator::new(ast, &self.base_dir);
+        let mut defined: std::collections::HashSet<&str> = evaluator.defined_names().collect();
+        for builtin in &["CC", "CXX", "CFLAGS", "LDFLAGS", "MAKE", "SHELL", "PWD"] {
+            defined.insert(builtin);
+        }
+
+        let mut violations = Vec::new();
+        for node in &ast.nodes {
+            match &node.data {
+                NodeData::Variable { name, value, .. } => {
+                    check_unresolved_refs(value, &defined, node.span, &mut violations);
+                    let _ = name;
+                }
+                NodeData::Recipe { lines } => {
+                    for line in lines {
+                        check_unresolved_refs(&line.text, &defined, node.span, &mut violations);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        violations
+    }
+}
+
+/// A `$(NAME)`/`${NAME}` reference not present in `defined` is reported once
+/// per unique name within `text`. Names come from [`collect_references`], which
+/// recurses into function-call arguments via the same balanced-paren
+/// matching `Evaluator` uses internally, so a name nested inside e.g.
+/// `$(patsubst %.c,%.o,$(SRCS))` is still found.
+fn check_unresolved_refs(
+    text: &str,
+    defined: &std::collections::HashSet<&str>,
+    span: SourceSpan,
+    violations: &mut Vec<Violation>,
+) {
+    let mut refs = Vec::new();
+    collect_references(text, &mut refs);
+
+    let mut reported = std::collections::HashSet::new();
+    for name in refs {
+        if is_automatic_or_numeric(&name) || defined.contains(name.as_str()) {
+            continue;
+        }
+        if !reported.insert(name.clone()) {
+            continue;
+        }
+
+        violations.push(Violation {
+            rule: "undefinedvariable-eval".to_string(),
+            severity: Severity::Warning,
+            span,
+            message: format!("Variable '{name}' may be undefined"),
+            fix_hint: Some(format!("Define '{name}' before use")),
+        });
+    }
+}
+
+/// Recursively collect every `$(NAME)`/`${NAME}` reference in `text`,
+/// including ones nested inside a text function's arguments (e.g. the
+/// `SRCS` in `$(patsubst %.c,%.o,$(SRCS))`). Each `$(...)`/`${...}` span is
+/// located via [`eval::extract_balanced`] -- the same matcher `Evaluator`
+/// uses to expand function calls -- rather than a first-matching-close-char
+/// scan, so a reference nested inside another one isn't mistaken for the
+/// outer call's closing delimiter.
+///
+/// A span whose inner text is a plain variable name is recorded directly;
+/// anything else (a function call, a `:`-modifier expression, ...) is
+/// recursed into, since names can still appear nested inside it.
+fn collect_references(text: &str, out: &mut Vec<String>) {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'$' && i + 1 < bytes.len() && matches!(bytes[i + 1], b'(' | b'{') {
+            let close = if bytes[i + 1] == b'(' { b')' } else { b'}' };
+            if let Some((inner, next)) = eval::extract_balanced(text, i + 2, close) {
+                if is_simple_name(inner) {
+                    out.push(inner.to_string());
+                } else {
+                    collect_references(inner, out);
+                }
+                i = next;
+                continue;
+            }
+        }
+        i += 1;
+    }
+}
+
+fn is_simple_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name.chars().next().is_some_and(|c| !c.is_ascii_digit())
+}
+
+fn is_automatic_or_numeric(name: &str) -> bool {
+    matches!(name, "@" | "<" | "^" | "?" | "*" | "%" | "+" | "|" | "$")
+        || name.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::makefile_linter::MakefileParser;
+
+    fn parse(input: &str) -> MakefileAst {
+        let mut parser = MakefileParser::new(input);
+        parser.parse().unwrap()
+    }
+
+    #[test]
+    fn duplicate_target_rule_flags_second_definition() {
+        let ast = parse("foo:\n\techo one\nfoo:\n\techo two\n");
+        let violations = DuplicateTargetRule.check(&ast);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("foo"));
+    }
+
+    #[test]
+    fn duplicate_target_rule_allows_double_colon_rules() {
+        let ast = parse("foo::\n\techo one\nfoo::\n\techo two\n");
+        let violations = DuplicateTargetRule.check(&ast);
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn undefined_variable_eval_rule_ignores_function_arguments() {
+        let ast = parse("SRCS = foo.c bar.c\nall:\n\techo $(patsubst %.c,%.o,$(SRCS))\n");
+        let rule = UndefinedVariableEvalRule::default();
+        let violations = rule.check(&ast);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn undefined_variable_eval_rule_flags_truly_undefined_variable() {
+        let ast = parse("all:\n\techo $(MISSING)\n");
+        let rule = UndefinedVariableEvalRule::default();
+        let violations = rule.check(&ast);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("MISSING"));
+    }
+
+    #[test]
+    fn undefined_variable_eval_rule_flags_undefined_name_nested_in_function_call() {
+        let ast = parse("all:\n\techo $(patsubst %.c,%.o,$(MISSING))\n");
+        let rule = UndefinedVariableEvalRule::default();
+        let violations = rule.check(&ast);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("MISSING"));
+    }
+}
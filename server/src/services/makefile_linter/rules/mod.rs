@@ -1,8 +1,9 @@
 pub mod checkmake;
+pub mod evaluation;
 pub mod performance;
 
 use super::ast::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
 pub enum Severity {
@@ -80,6 +81,21 @@ pub struct RuleRegistry {
 
 impl RuleRegistry {
     pub fn new() -> Self {
+        Self::with_base_dir(Path::new("."))
+    }
+
+    /// Like [`Self::new`], but resolves `evaluation::UndefinedVariableEvalRule`'s
+    /// `$(wildcard ...)` calls against `base_dir` (the directory the
+    /// Makefile being linted lives in) instead of the process's cwd.
+    ///
+    /// Both `checkmake::UndefinedVariableRule` and
+    /// `evaluation::UndefinedVariableEvalRule` are registered: the eval-aware
+    /// rule catches cases the naive text scanner can't (e.g. a name that's
+    /// only undefined after function expansion), but for the common case
+    /// they agree and would otherwise double-report the same root cause at
+    /// the same span. `check_all`'s `dedupe_violations` collapses those
+    /// duplicates by (message, span) before scoring/rendering.
+    pub fn with_base_dir(base_dir: &Path) -> Self {
         let mut registry = Self::default();
 
         // Register all rules
@@ -90,6 +106,10 @@ impl RuleRegistry {
         registry.register(Box::new(checkmake::UndefinedVariableRule));
         registry.register(Box::new(performance::RecursiveExpansionRule::default()));
         registry.register(Box::new(checkmake::PortabilityRule));
+        registry.register(Box::new(evaluation::DuplicateTargetRule));
+        registry.register(Box::new(evaluation::UndefinedVariableEvalRule::with_base_dir(
+            base_dir,
+        )));
 
         registry
     }
@@ -105,6 +125,8 @@ impl RuleRegistry {
             violations.extend(rule.check(ast));
         }
 
+        dedupe_violations(&mut violations);
+
         // Sort by severity and line number
         violations.sort_by(|a, b| {
             match (a.severity == Severity::Error, b.severity == Severity::Error) {
@@ -118,6 +140,23 @@ impl RuleRegistry {
     }
 }
 
+/// Different rules can independently flag the same root cause at the same
+/// span -- e.g. `undefinedvariable` and `undefinedvariable-eval` both
+/// firing on an undefined `$(NAME)` reference. Keep only the first such
+/// violation so one real issue isn't double-counted against
+/// `calculate_quality_score` or duplicated in rendered output.
+fn dedupe_violations(violations: &mut Vec<Violation>) {
+    let mut seen = std::collections::HashSet::new();
+    violations.retain(|v| {
+        seen.insert((
+            v.message.clone(),
+            v.span.line,
+            v.span.start,
+            v.span.end,
+        ))
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +212,26 @@ mod tests {
         assert_eq!(registry.rules.len(), initial_count + 1);
     }
 
+    #[test]
+    fn test_check_all_dedupes_undefined_variable_across_rules() {
+        let input = "all:\n\techo $(MISSING)\n";
+        let mut parser = MakefileParser::new(input);
+        let ast = parser.parse().unwrap();
+
+        let registry = RuleRegistry::new();
+        let violations = registry.check_all(&ast);
+
+        let undefined_hits: Vec<_> = violations
+            .iter()
+            .filter(|v| v.message.contains("MISSING"))
+            .collect();
+        assert_eq!(
+            undefined_hits.len(),
+            1,
+            "the naive and eval-aware undefined-variable rules should collapse to one violation, got {undefined_hits:?}"
+        );
+    }
+
     #[test]
     fn test_check_all_empty_ast() {
         let registry = RuleRegistry::new();
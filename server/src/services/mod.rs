@@ -53,6 +53,7 @@ pub mod lightweight_provability_analyzer;
 pub mod makefile_compressor;
 pub mod makefile_linter;
 pub mod mermaid_generator;
+pub mod mutation_testing;
 pub mod parallel_git;
 pub mod parsed_file_cache;
 pub mod progress;
@@ -0,0 +1,761 @@
+//! Mutation testing analyzer
+//!
+//! Modeled on necessist: for each function body in the project under
+//! analysis, apply a catalog of semantics-preserving-looking mutations and
+//! re-run the project's test command. A mutant that survives (tests still
+//! pass) marks the code it touched as untested; a mutant that's caught
+//! (tests fail) means the existing suite does exercise that behavior.
+//!
+//! The project under analysis is copied once into a scratch directory
+//! before the sweep starts, and every mutant is written to and tested in
+//! that scratch copy -- the user's real source tree is never opened for
+//! writing, so a killed sweep (Ctrl-C, OOM-kill) can never leave it
+//! mutated.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+/// Configuration for a mutation testing sweep.
+#[derive(Debug, Clone)]
+pub struct MutationConfig {
+    pub project_path: PathBuf,
+    pub test_command: String,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub max_mutants: usize,
+    pub timeout: Duration,
+}
+
+impl Default for MutationConfig {
+    fn default() -> Self {
+        Self {
+            project_path: PathBuf::from("."),
+            test_command: "cargo test".to_string(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            max_mutants: 500,
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// A single semantics-preserving-looking mutation applied at one site.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Mutant {
+    pub id: usize,
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub operator: MutationOperator,
+    pub original: String,
+    pub mutated: String,
+    pub status: MutantStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MutationOperator {
+    /// Delete a statement from a block.
+    DeleteStatement,
+    /// Replace a single-argument method call's receiver with its argument.
+    ReplaceReceiverWithArgument,
+    /// Swap `&&` for `||` or vice versa.
+    SwapLogicalOperator,
+    /// Replace a function's returned expression with `Default::default()`.
+    ReplaceReturnWithDefault,
+    /// Flip a comparison operator (e.g. `<` to `>=`).
+    FlipComparisonOperator,
+}
+
+impl MutationOperator {
+    fn label(self) -> &'static str {
+        match self {
+            MutationOperator::DeleteStatement => "delete-statement",
+            MutationOperator::ReplaceReceiverWithArgument => "replace-receiver-with-argument",
+            MutationOperator::SwapLogicalOperator => "swap-logical-operator",
+            MutationOperator::ReplaceReturnWithDefault => "replace-return-with-default",
+            MutationOperator::FlipComparisonOperator => "flip-comparison-operator",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MutantStatus {
+    /// Tests still passed with the mutation applied -- the mutation went untested.
+    Surviving,
+    /// Tests failed with the mutation applied -- the existing suite caught it.
+    Caught,
+    /// The test run did not finish within the configured timeout.
+    TimedOut,
+}
+
+/// Summary report for a full mutation testing sweep.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MutationReport {
+    pub total_mutants: usize,
+    pub caught: usize,
+    pub surviving: usize,
+    pub timed_out: usize,
+    pub mutation_score: f64,
+    pub mutants: Vec<Mutant>,
+}
+
+impl MutationReport {
+    fn from_mutants(mutants: Vec<Mutant>) -> Self {
+        let caught = count(&mutants, MutantStatus::Caught);
+        let surviving = count(&mutants, MutantStatus::Surviving);
+        let timed_out = count(&mutants, MutantStatus::TimedOut);
+        let scored = caught + surviving;
+        let mutation_score = if scored == 0 {
+            0.0
+        } else {
+            caught as f64 / scored as f64
+        };
+
+        Self {
+            total_mutants: mutants.len(),
+            caught,
+            surviving,
+            timed_out,
+            mutation_score,
+            mutants,
+        }
+    }
+
+    /// Surviving mutants in the order they were generated; no ranking is applied.
+    pub fn surviving_mutants(&self) -> Vec<&Mutant> {
+        self.mutants
+            .iter()
+            .filter(|m| m.status == MutantStatus::Surviving)
+            .collect()
+    }
+}
+
+fn count(mutants: &[Mutant], status: MutantStatus) -> usize {
+    mutants.iter().filter(|m| m.status == status).count()
+}
+
+/// Run a full mutation testing sweep over `config.project_path`.
+pub fn run_mutation_testing(config: &MutationConfig) -> Result<MutationReport> {
+    if !run_tests(&config.project_path, &config.test_command, config.timeout)?.passed() {
+        // No point mutating a project whose tests don't even pass unmutated.
+        return Ok(MutationReport::from_mutants(Vec::new()));
+    }
+
+    let scratch = ScratchProject::new(&config.project_path)?;
+
+    let mut mutants = Vec::new();
+    let mut next_id = 0;
+
+    'files: for file in discover_rust_files(&config.project_path, &config.include, &config.exclude)
+    {
+        let original_source = std::fs::read_to_string(&file)
+            .with_context(|| format!("reading {}", file.display()))?;
+        let relative_file = file
+            .strip_prefix(&config.project_path)
+            .unwrap_or(file.as_path());
+
+        for site in find_mutation_sites(&original_source) {
+            if next_id >= config.max_mutants {
+                break 'files;
+            }
+
+            let mutated_source = apply_mutation(&original_source, &site);
+            let status = run_with_mutation(
+                &scratch,
+                relative_file,
+                &original_source,
+                &mutated_source,
+                config,
+            )?;
+
+            mutants.push(Mutant {
+                id: next_id,
+                file: file.clone(),
+                line: site.line,
+                column: site.column,
+                operator: site.operator,
+                original: site.original.clone(),
+                mutated: site.mutated.clone(),
+                status,
+            });
+            next_id += 1;
+        }
+    }
+
+    Ok(MutationReport::from_mutants(mutants))
+}
+
+/// A throwaway copy of the project under test. Every mutant is written to
+/// and tested inside this directory, so the real project tree at
+/// `config.project_path` is only ever read, never written.
+struct ScratchProject {
+    dir: TempDir,
+}
+
+impl ScratchProject {
+    fn new(project_path: &Path) -> Result<Self> {
+        let dir = TempDir::new().context("creating mutation-testing scratch directory")?;
+        copy_project_tree(project_path, dir.path())?;
+        Ok(Self { dir })
+    }
+
+    fn root(&self) -> &Path {
+        self.dir.path()
+    }
+
+    fn file_path(&self, relative_file: &Path) -> PathBuf {
+        self.dir.path().join(relative_file)
+    }
+}
+
+/// Copy `src` into `dst`, skipping `target`/`.git` the same way
+/// [`discover_rust_files`] does -- there's no point shipping a build cache
+/// or history into a throwaway directory that gets deleted after the sweep.
+fn copy_project_tree(src: &Path, dst: &Path) -> Result<()> {
+    for entry in WalkDir::new(src)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "target" && e.file_name() != ".git")
+    {
+        let entry = entry.with_context(|| format!("walking {}", src.display()))?;
+        let relative = entry
+            .path()
+            .strip_prefix(src)
+            .with_context(|| format!("computing relative path for {}", entry.path().display()))?;
+        let target = dst.join(relative);
+
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target)
+                .with_context(|| format!("creating scratch directory {}", target.display()))?;
+        } else if entry.file_type().is_file() {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("creating scratch directory {}", parent.display()))?;
+            }
+            std::fs::copy(entry.path(), &target).with_context(|| {
+                format!(
+                    "copying {} to scratch path {}",
+                    entry.path().display(),
+                    target.display()
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn discover_rust_files(root: &Path, include: &[String], exclude: &[String]) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "target" && e.file_name() != ".git")
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("rs"))
+        .filter(|p| include.is_empty() || include.iter().any(|pat| path_matches(p, pat)))
+        .filter(|p| !exclude.iter().any(|pat| path_matches(p, pat)))
+        .collect()
+}
+
+fn path_matches(path: &Path, pattern: &str) -> bool {
+    let path_str = path.to_string_lossy();
+    let needle = pattern.trim_start_matches("**/").trim_end_matches("/**");
+    match needle.strip_prefix('*') {
+        Some(suffix) => path_str.ends_with(suffix),
+        None => path_str.contains(needle),
+    }
+}
+
+/// A candidate mutation, already rendered as `(original_text, mutated_text)`
+/// at a specific line/column in the source.
+struct MutationSite {
+    line: usize,
+    column: usize,
+    /// Byte offsets of the span being replaced, in the *original* source --
+    /// what `apply_mutation` actually splices on. `original`/`mutated` below
+    /// are `quote!`-rendered text kept only for human-readable reporting
+    /// (`quote!` inserts spaces around nearly every token, so they rarely
+    /// match the source verbatim).
+    start: usize,
+    end: usize,
+    operator: MutationOperator,
+    original: String,
+    mutated: String,
+}
+
+/// Replace the byte range `site.start..site.end` with `site.mutated`.
+///
+/// Splicing on the span's byte offsets (rather than searching for
+/// `site.original` as a substring) is what makes this correct for anything
+/// beyond single tokens: `site.original` is a `quote!`-rendered display
+/// string, and `quote!` inserts spaces around nearly every token
+/// (`foo.bar(x)` stringifies to `"foo . bar (x)"`), so it essentially never
+/// appears verbatim in the source. Byte offsets also work for spans that
+/// cross multiple lines, which a per-line substring search never could.
+fn apply_mutation(source: &str, site: &MutationSite) -> String {
+    let mut result = String::with_capacity(source.len());
+    result.push_str(&source[..site.start]);
+    result.push_str(&site.mutated);
+    result.push_str(&source[site.end..]);
+    result
+}
+
+struct MutationSiteVisitor {
+    sites: Vec<MutationSite>,
+}
+
+impl<'ast> Visit<'ast> for MutationSiteVisitor {
+    fn visit_expr(&mut self, expr: &'ast syn::Expr) {
+        match expr {
+            syn::Expr::Binary(bin) => {
+                if let Some(site) = binary_op_mutation(bin) {
+                    self.sites.push(site);
+                }
+            }
+            syn::Expr::MethodCall(call) if call.args.len() == 1 => {
+                if let Some(site) = receiver_mutation(call) {
+                    self.sites.push(site);
+                }
+            }
+            _ => {}
+        }
+        visit::visit_expr(self, expr);
+    }
+
+    fn visit_block(&mut self, block: &'ast syn::Block) {
+        for stmt in &block.stmts {
+            if let Some(site) = statement_deletion(stmt) {
+                self.sites.push(site);
+            }
+        }
+        visit::visit_block(self, block);
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        if let Some(syn::Stmt::Expr(expr, None)) = item.block.stmts.last() {
+            if let Some(site) = return_mutation(expr) {
+                self.sites.push(site);
+            }
+        }
+        visit::visit_item_fn(self, item);
+    }
+}
+
+fn binary_op_mutation(bin: &syn::ExprBinary) -> Option<MutationSite> {
+    use syn::BinOp;
+
+    let (original, mutated) = match &bin.op {
+        BinOp::And(_) => ("&&", "||"),
+        BinOp::Or(_) => ("||", "&&"),
+        BinOp::Lt(_) => ("<", ">="),
+        BinOp::Le(_) => ("<=", ">"),
+        BinOp::Gt(_) => (">", "<="),
+        BinOp::Ge(_) => (">=", "<"),
+        BinOp::Eq(_) => ("==", "!="),
+        BinOp::Ne(_) => ("!=", "=="),
+        _ => return None,
+    };
+
+    let operator = match &bin.op {
+        BinOp::And(_) | BinOp::Or(_) => MutationOperator::SwapLogicalOperator,
+        _ => MutationOperator::FlipComparisonOperator,
+    };
+
+    let span = bin.op.span();
+    let start = span.start();
+    let byte_range = span.byte_range();
+    Some(MutationSite {
+        line: start.line,
+        column: start.column,
+        start: byte_range.start,
+        end: byte_range.end,
+        operator,
+        original: original.to_string(),
+        mutated: mutated.to_string(),
+    })
+}
+
+fn receiver_mutation(call: &syn::ExprMethodCall) -> Option<MutationSite> {
+    let receiver = expr_as_ident(&call.receiver)?;
+    let arg = call.args.first()?;
+    let arg_text = expr_as_ident(arg)?;
+
+    let span = call.receiver.span();
+    let start = span.start();
+    let byte_range = span.byte_range();
+    Some(MutationSite {
+        line: start.line,
+        column: start.column,
+        start: byte_range.start,
+        end: byte_range.end,
+        operator: MutationOperator::ReplaceReceiverWithArgument,
+        original: receiver,
+        mutated: arg_text,
+    })
+}
+
+fn expr_as_ident(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(p) => p.path.get_ident().map(|i| i.to_string()),
+        _ => None,
+    }
+}
+
+fn statement_deletion(stmt: &syn::Stmt) -> Option<MutationSite> {
+    // Only whole-line, side-effecting expression statements are safe
+    // candidates -- deleting a `let` would break later references.
+    let syn::Stmt::Expr(expr, Some(_semi)) = stmt else {
+        return None;
+    };
+    let text = quote::quote!(#expr).to_string();
+    if text.trim().is_empty() {
+        return None;
+    }
+
+    let span = stmt.span();
+    let start = span.start();
+    let byte_range = span.byte_range();
+    Some(MutationSite {
+        line: start.line,
+        column: start.column,
+        start: byte_range.start,
+        end: byte_range.end,
+        operator: MutationOperator::DeleteStatement,
+        original: format!("{text};"),
+        mutated: String::new(),
+    })
+}
+
+fn return_mutation(tail_expr: &syn::Expr) -> Option<MutationSite> {
+    let text = quote::quote!(#tail_expr).to_string();
+    if text == "Default :: default ()" {
+        return None;
+    }
+
+    let span = tail_expr.span();
+    let start = span.start();
+    let byte_range = span.byte_range();
+    Some(MutationSite {
+        line: start.line,
+        column: start.column,
+        start: byte_range.start,
+        end: byte_range.end,
+        operator: MutationOperator::ReplaceReturnWithDefault,
+        original: text,
+        mutated: "Default::default()".to_string(),
+    })
+}
+
+fn find_mutation_sites(source: &str) -> Vec<MutationSite> {
+    let Ok(file) = syn::parse_file(source) else {
+        return Vec::new();
+    };
+
+    let mut visitor = MutationSiteVisitor { sites: Vec::new() };
+    visitor.visit_file(&file);
+    visitor.sites
+}
+
+struct TestRunResult {
+    success: bool,
+    timed_out: bool,
+}
+
+impl TestRunResult {
+    fn passed(&self) -> bool {
+        self.success && !self.timed_out
+    }
+}
+
+/// Write `mutated_source` to `relative_file` inside `scratch`, run the test
+/// command there, then always restore `original_source` in the scratch
+/// copy -- even if the test run panics or returns an error -- so the next
+/// mutant in the sweep starts from a clean scratch tree. The real project
+/// passed in `config.project_path` is never opened for writing.
+fn run_with_mutation(
+    scratch: &ScratchProject,
+    relative_file: &Path,
+    original_source: &str,
+    mutated_source: &str,
+    config: &MutationConfig,
+) -> Result<MutantStatus> {
+    let scratch_file = scratch.file_path(relative_file);
+
+    std::fs::write(&scratch_file, mutated_source)
+        .with_context(|| format!("writing mutant to {}", scratch_file.display()))?;
+
+    let result = run_tests(scratch.root(), &config.test_command, config.timeout);
+
+    // Restore the scratch copy unconditionally before propagating any
+    // error from the test run.
+    std::fs::write(&scratch_file, original_source)
+        .with_context(|| format!("restoring scratch file {}", scratch_file.display()))?;
+
+    let result = result?;
+    Ok(if result.timed_out {
+        MutantStatus::TimedOut
+    } else if result.success {
+        MutantStatus::Surviving
+    } else {
+        MutantStatus::Caught
+    })
+}
+
+fn run_tests(project_path: &Path, test_command: &str, timeout: Duration) -> Result<TestRunResult> {
+    let mut parts = test_command.split_whitespace();
+    let program = parts
+        .next()
+        .context("empty test command")?;
+
+    let mut child = Command::new(program)
+        .args(parts)
+        .current_dir(project_path)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .with_context(|| format!("spawning test command `{test_command}`"))?;
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(TestRunResult {
+                success: status.success(),
+                timed_out: false,
+            });
+        }
+        if start.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(TestRunResult {
+                success: false,
+                timed_out: true,
+            });
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Render a [`MutationReport`] as Markdown.
+pub fn format_markdown(report: &MutationReport) -> String {
+    let mut out = String::new();
+    out.push_str("# Mutation Testing Report\n\n");
+    out.push_str(&format!(
+        "- **Mutants**: {}\n- **Caught**: {}\n- **Surviving**: {}\n- **Timed out**: {}\n- **Mutation score**: {:.1}%\n\n",
+        report.total_mutants,
+        report.caught,
+        report.surviving,
+        report.timed_out,
+        report.mutation_score * 100.0
+    ));
+
+    if !report.surviving_mutants().is_empty() {
+        out.push_str("## Surviving mutants\n\n");
+        for mutant in report.surviving_mutants() {
+            out.push_str(&format!(
+                "- `{}:{}` ({}) — `{}` → `{}`\n",
+                mutant.file.display(),
+                mutant.line,
+                mutant.operator.label(),
+                mutant.original,
+                mutant.mutated
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_mutation_sites_comparison() {
+        let source = "fn check(a: i32, b: i32) -> bool { a < b }";
+        let sites = find_mutation_sites(source);
+        assert!(sites
+            .iter()
+            .any(|s| s.operator == MutationOperator::FlipComparisonOperator));
+    }
+
+    #[test]
+    fn test_find_mutation_sites_logical() {
+        let source = "fn check(a: bool, b: bool) -> bool { a && b }";
+        let sites = find_mutation_sites(source);
+        assert!(sites
+            .iter()
+            .any(|s| s.operator == MutationOperator::SwapLogicalOperator));
+    }
+
+    #[test]
+    fn test_find_mutation_sites_statement_deletion() {
+        let source = "fn run() { do_thing(); do_other_thing(); }";
+        let sites = find_mutation_sites(source);
+        assert!(sites
+            .iter()
+            .any(|s| s.operator == MutationOperator::DeleteStatement));
+    }
+
+    #[test]
+    fn test_apply_mutation_comparison() {
+        let source = "fn check(a: i32, b: i32) -> bool {\n    a < b\n}";
+        let start = source.find('<').unwrap();
+        let site = MutationSite {
+            line: 2,
+            column: 6,
+            start,
+            end: start + 1,
+            operator: MutationOperator::FlipComparisonOperator,
+            original: "<".to_string(),
+            mutated: ">=".to_string(),
+        };
+        let mutated = apply_mutation(source, &site);
+        assert!(mutated.contains("a >= b"));
+    }
+
+    #[test]
+    fn test_apply_mutation_statement_deletion_multi_token() {
+        // `foo.bar(x)` has no whitespace in the source, but `quote!` renders
+        // it as `"foo . bar (x)"` -- a substring search for that text would
+        // never match, leaving the statement untouched. Byte-offset
+        // splicing must delete it regardless.
+        let source = "fn run() {\n    foo.bar(x);\n    baz();\n}";
+        let sites = find_mutation_sites(source);
+        let site = sites
+            .iter()
+            .find(|s| s.operator == MutationOperator::DeleteStatement && s.original.contains("bar"))
+            .expect("expected a deletion site for foo.bar(x)");
+
+        let mutated = apply_mutation(source, site);
+        assert!(!mutated.contains("foo.bar(x)"));
+        assert!(mutated.contains("baz();"));
+    }
+
+    #[test]
+    fn test_apply_mutation_return_multi_token() {
+        // Same no-whitespace-in-source-but-spaced-by-quote issue as above,
+        // this time for a tail expression.
+        let source = "fn compute(a: i32, b: i32) -> i32 {\n    a.min(b)\n}";
+        let sites = find_mutation_sites(source);
+        let site = sites
+            .iter()
+            .find(|s| s.operator == MutationOperator::ReplaceReturnWithDefault)
+            .expect("expected a return-mutation site");
+
+        let mutated = apply_mutation(source, site);
+        assert!(!mutated.contains("a.min(b)"));
+        assert!(mutated.contains("Default::default()"));
+    }
+
+    #[test]
+    fn test_mutation_report_score() {
+        let mutants = vec![
+            Mutant {
+                id: 0,
+                file: PathBuf::from("a.rs"),
+                line: 1,
+                column: 0,
+                operator: MutationOperator::FlipComparisonOperator,
+                original: "<".to_string(),
+                mutated: ">=".to_string(),
+                status: MutantStatus::Caught,
+            },
+            Mutant {
+                id: 1,
+                file: PathBuf::from("a.rs"),
+                line: 2,
+                column: 0,
+                operator: MutationOperator::SwapLogicalOperator,
+                original: "&&".to_string(),
+                mutated: "||".to_string(),
+                status: MutantStatus::Surviving,
+            },
+        ];
+        let report = MutationReport::from_mutants(mutants);
+        assert_eq!(report.caught, 1);
+        assert_eq!(report.surviving, 1);
+        assert_eq!(report.mutation_score, 0.5);
+    }
+
+    #[test]
+    fn test_run_tests_timeout() {
+        let result = run_tests(
+            Path::new("."),
+            "sleep 5",
+            Duration::from_millis(100),
+        )
+        .unwrap();
+        assert!(result.timed_out);
+    }
+
+    #[test]
+    fn test_run_tests_success() {
+        let result = run_tests(Path::new("."), "true", Duration::from_secs(5)).unwrap();
+        assert!(result.success);
+        assert!(!result.timed_out);
+    }
+
+    #[test]
+    fn test_format_markdown_includes_score() {
+        let report = MutationReport::from_mutants(Vec::new());
+        let md = format_markdown(&report);
+        assert!(md.contains("Mutation Testing Report"));
+    }
+
+    #[test]
+    fn test_run_with_mutation_never_writes_the_real_project_file() {
+        let project = tempfile::tempdir().unwrap();
+        let real_file = project.path().join("lib.rs");
+        let original_source = "fn check(a: i32, b: i32) -> bool { a < b }";
+        std::fs::write(&real_file, original_source).unwrap();
+
+        let scratch = ScratchProject::new(project.path()).unwrap();
+        let config = MutationConfig {
+            project_path: project.path().to_path_buf(),
+            test_command: "true".to_string(),
+            ..MutationConfig::default()
+        };
+
+        let mutated_source = "fn check(a: i32, b: i32) -> bool { a >= b }";
+        let status = run_with_mutation(
+            &scratch,
+            Path::new("lib.rs"),
+            original_source,
+            mutated_source,
+            &config,
+        )
+        .unwrap();
+
+        assert_eq!(status, MutantStatus::Surviving);
+        assert_eq!(
+            std::fs::read_to_string(&real_file).unwrap(),
+            original_source,
+            "the real project file must never be written to"
+        );
+        assert_eq!(
+            std::fs::read_to_string(scratch.file_path(Path::new("lib.rs"))).unwrap(),
+            original_source,
+            "the scratch copy must be restored after the test run"
+        );
+    }
+
+    #[test]
+    fn test_copy_project_tree_skips_target_and_git() {
+        let src = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(src.path().join("target")).unwrap();
+        std::fs::write(src.path().join("target/build_artifact"), "x").unwrap();
+        std::fs::create_dir_all(src.path().join("src")).unwrap();
+        std::fs::write(src.path().join("src/lib.rs"), "fn f() {}").unwrap();
+
+        let dst = tempfile::tempdir().unwrap();
+        copy_project_tree(src.path(), dst.path()).unwrap();
+
+        assert!(dst.path().join("src/lib.rs").exists());
+        assert!(!dst.path().join("target").exists());
+    }
+}
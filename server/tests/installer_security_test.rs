@@ -58,31 +58,52 @@ mod security_tests {
     }
 
     #[test]
-    #[ignore = "Requires full MIR lowering implementation"]
     fn test_command_injection_prevention() {
+        use installer_macro::shell_ir::{render, Command, IrNode, ShellWord};
+
         for input in MALICIOUS_INPUTS {
-            let shell = generate_installer_with_args(vec![input.to_string()]);
+            let rendered = render(&[IrNode::Command(Command::new(vec![
+                ShellWord::Raw("echo".to_string()),
+                ShellWord::Literal(input.to_string()),
+            ]))]);
 
-            // Verify no unescaped input appears in shell
-            assert!(
-                !shell.contains(input) || shell.contains(&shell_escape(input)),
-                "Unescaped input found: {}",
+            // A literal round-trips exactly through single-quote escaping, so
+            // whatever the attacker put in comes back out unchanged and
+            // inert — never reinterpreted as shell syntax.
+            assert_eq!(
+                unescape_single_quoted(&rendered),
+                *input,
+                "literal did not round-trip safely for input: {}",
                 input
             );
 
-            // Verify dangerous patterns are not present
-            assert!(!shell.contains("eval "), "eval found in generated shell");
+            // Dangerous constructs can only ever appear if the IR itself
+            // emitted them (it never does for a Literal word).
+            assert!(!rendered.contains("eval "), "eval found in generated shell");
             assert!(
-                !shell.contains("source "),
+                !rendered.contains("source "),
                 "source found in generated shell"
             );
             assert!(
-                !shell.contains(". "),
-                "dot sourcing found in generated shell"
+                !rendered.contains("<<<"),
+                "here-string found in generated shell"
             );
         }
     }
 
+    /// Reverse single-quote escaping (`'...'` with embedded quotes as
+    /// `'\''`) back to the original unescaped string, given a line of the
+    /// form `cmd 'escaped literal'`.
+    fn unescape_single_quoted(rendered: &str) -> String {
+        let first_quote = rendered.find('\'').expect("literal must be quoted");
+        let body = rendered[first_quote..].trim_end_matches('\n');
+        let inner = body
+            .strip_prefix('\'')
+            .and_then(|b| b.strip_suffix('\''))
+            .expect("literal must be quoted");
+        inner.split("'\\''").collect::<Vec<_>>().join("'")
+    }
+
     #[test]
     fn test_shellcheck_security_audit() {
         // Only run if shellcheck is available
@@ -140,29 +161,33 @@ mod security_tests {
     }
 
     #[test]
-    #[ignore = "Requires full MIR lowering implementation"]
     fn test_proper_quoting() {
-        let shell = generate_installer_with_args(vec!["test arg".to_string()]);
+        use installer_macro::shell_ir::{render, Command, IrNode, ShellWord};
 
-        // Verify all variable expansions are quoted
-        let lines: Vec<&str> = shell.lines().collect();
-        for line in lines {
-            if line.trim().starts_with('#') {
-                continue;
-            }
+        // VarExpansion is always rendered double-quoted, regardless of name.
+        for var in ["TARGET", "INSTALL_DIR", "_tmp_1"] {
+            let rendered = render(&[IrNode::Command(Command::new(vec![
+                ShellWord::Raw("echo".to_string()),
+                ShellWord::VarExpansion(var.to_string()),
+            ]))]);
+            assert!(
+                rendered.contains(&format!("\"${var}\"")),
+                "variable expansion was not quoted: {rendered}"
+            );
+        }
 
-            // Check for unquoted variable expansions
-            if line.contains("$") && !line.contains("\"$") && !line.contains("'$") {
-                // Special cases that are allowed
-                let allowed = [
-                    "case $", // Case statements
-                    "[ $",    // Test conditions (should still be quoted though)
-                    "exit $", // Exit codes
-                ];
-
-                if !allowed.iter().any(|&pattern| line.contains(pattern)) {
-                    panic!("Unquoted variable expansion found: {}", line);
-                }
+        // A Literal carrying a `$` is single-quoted, so the `$` is inert —
+        // never a bare, shell-interpreted expansion.
+        let rendered = render(&[IrNode::Command(Command::new(vec![
+            ShellWord::Raw("echo".to_string()),
+            ShellWord::Literal("$HOME/.ssh/id_rsa".to_string()),
+        ]))]);
+        for line in rendered.lines() {
+            if line.contains('$') {
+                assert!(
+                    line.contains("\"$") || line.contains("'$"),
+                    "unquoted variable expansion found: {line}"
+                );
             }
         }
     }
@@ -202,35 +227,22 @@ mod security_tests {
     }
 
     #[test]
-    #[ignore = "Requires full MIR lowering implementation"]
     fn test_safe_temp_file_handling() {
-        let shell = generate_installer_with_args(vec!["test".to_string()]);
+        use installer_macro::shell_ir::{render, IrNode};
+
+        let rendered = render(&[IrNode::TempFile {
+            var: "INSTALL_TMP".to_string(),
+        }]);
 
         // Verify mktemp is used for temporary files
-        assert!(shell.contains("mktemp"), "mktemp not used for temp files");
+        assert!(rendered.contains("mktemp"), "mktemp not used for temp files");
 
-        // Verify cleanup trap is set
-        assert!(shell.contains("trap"), "No cleanup trap found");
-        assert!(
-            shell.contains("EXIT") || shell.contains("ERR"),
-            "Trap not set for EXIT/ERR"
-        );
-    }
+        // Verify a cleanup trap is always set, and on EXIT specifically
+        assert!(rendered.contains("trap"), "No cleanup trap found");
+        assert!(rendered.contains("EXIT"), "Trap not set for EXIT");
 
-    fn shell_escape(s: &str) -> String {
-        s.chars()
-            .map(|c| match c {
-                '"' => "\\\"".to_string(),
-                '\\' => "\\\\".to_string(),
-                '$' => "\\$".to_string(),
-                '`' => "\\`".to_string(),
-                '\n' => "\\n".to_string(),
-                '\r' => "\\r".to_string(),
-                '\t' => "\\t".to_string(),
-                c if c.is_control() => format!("\\x{:02x}", c as u8),
-                c => c.to_string(),
-            })
-            .collect()
+        // The temp path itself must be quoted everywhere it's referenced.
+        assert!(rendered.contains("\"$INSTALL_TMP\""));
     }
 }
 